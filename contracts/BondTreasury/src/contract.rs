@@ -0,0 +1,325 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+
+use cosmwasm_std::{
+    Addr, to_binary, DepsMut, Env, MessageInfo, Response, Uint128, CosmosMsg, WasmMsg
+};
+use cw2::set_contract_version;
+use lib::migrate::migrate_version;
+
+use crate::error::ContractError;
+use BondTreasury::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, Asset};
+use crate::state::{
+    OWNER, OWNERSHIP, TOMB, TOMB_ORACLE, TREASURY, DAO, ASSETS, VESTING, BOND_THRESHOLD,
+    BOND_FACTOR, SECONDARY_THRESHOLD, SECONDARY_FACTOR, BOND_VESTING, TOTAL_VESTED, TOMB_FOR_SALE
+};
+
+use crate::util::{check_onlyowner, check_only_asset, get_tomb_return, get_vesting, claim_vested};
+use terraswap::querier::{query_token_balance};
+use BasisAsset::msg::{ExecuteMsg as BasisAssetMsg};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "BondTreasury";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const DENOMINATOR: u128 = 1_000_000;//1e6
+pub const DAY: u128 = 86_400;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    OWNER.save(deps.storage, &info.sender)?;
+
+    TOMB.save(deps.storage, &msg.tomb)?;
+    TOMB_ORACLE.save(deps.storage, &msg.tomb_oracle)?;
+    TREASURY.save(deps.storage, &msg.treasury)?;
+    DAO.save(deps.storage, &msg.dao)?;
+
+    // 2% premium threshold, 80% factor up to 7%, 15% factor above, 3 days vesting
+    BOND_THRESHOLD.save(deps.storage, &Uint128::from(20_000u128))?;
+    BOND_FACTOR.save(deps.storage, &Uint128::from(800_000u128))?;
+    SECONDARY_THRESHOLD.save(deps.storage, &Uint128::from(70_000u128))?;
+    SECONDARY_FACTOR.save(deps.storage, &Uint128::from(150_000u128))?;
+    BOND_VESTING.save(deps.storage, &Uint128::from(3 * DAY))?;
+    TOTAL_VESTED.save(deps.storage, &Uint128::zero())?;
+    TOMB_FOR_SALE.save(deps.storage, &Uint128::zero())?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    let previous = migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[])?;
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", previous.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Bond { token, amount }
+            => try_bond(deps, env, info, token, amount),
+
+        ExecuteMsg::ClaimRewards {  }
+            => try_claim_rewards(deps, env, info),
+
+        ExecuteMsg::TopUp { amount }
+            => try_top_up(deps, env, info, amount),
+
+        ExecuteMsg::SetTomb { tomb }
+            => try_set_tomb(deps, info, tomb),
+
+        ExecuteMsg::SetTombOracle{ tomb_oracle }
+            => try_set_tomb_oracle(deps, info, tomb_oracle),
+
+        ExecuteMsg::SetTreasury { treasury }
+            => try_set_treasury(deps, info, treasury),
+
+        ExecuteMsg::SetDao { dao }
+            => try_set_dao(deps, info, dao),
+
+        ExecuteMsg::SetAsset { token, is_added, multiplier, oracle, is_lp, pair }
+            => try_set_asset(deps, info, token, Asset{ is_added, multiplier, oracle, is_lp, pair }),
+
+        ExecuteMsg::SetBondParameter { primary_threshold, primary_factor, second_threshold, second_factor, vesting_period }
+            => try_set_bond_parameter(deps, info, primary_threshold, primary_factor, second_threshold, second_factor, vesting_period),
+
+        ExecuteMsg::ProposeOperator { operator }
+            => Ok(OWNERSHIP.propose(deps.storage, &info.sender, operator)?),
+
+        ExecuteMsg::AcceptOperator {  }
+            => Ok(OWNERSHIP.accept(deps.storage, &info.sender)?),
+
+        ExecuteMsg::CancelProposal {  }
+            => Ok(OWNERSHIP.cancel(deps.storage, &info.sender)?),
+
+        ExecuteMsg::RenounceOperator {  }
+            => Ok(OWNERSHIP.renounce(deps.storage, &info.sender)?),
+    }
+}
+    // Bond asset for discounted Tomb at bond rate
+pub fn try_bond(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token: Addr,
+    amount: Uint128
+)
+    -> Result<Response, ContractError>
+{
+    check_only_asset(deps.storage, token.clone())?;
+    if amount == Uint128::zero() {
+        return Err(ContractError::BondTreasuryError { msg: "invalid bond amount".to_string() });
+    }
+    let tomb_amount = get_tomb_return(deps.storage, &deps.querier, token.clone(), amount)?;
+    if tomb_amount == Uint128::zero() {
+        return Err(ContractError::BondTreasuryError { msg: "bond returns no tomb".to_string() });
+    }
+
+    let tomb_for_sale = TOMB_FOR_SALE.load(deps.storage)?;
+    if tomb_amount > tomb_for_sale {
+        return Err(ContractError::BondTreasuryError { msg: "insufficient tomb for sale".to_string() });
+    }
+    TOMB_FOR_SALE.save(deps.storage, &(tomb_for_sale - tomb_amount))?;
+
+    let mut msgs: Vec<CosmosMsg> = Vec::new();
+    let msg_transfer_from = WasmMsg::Execute {
+        contract_addr: token.to_string(),
+        msg: to_binary(
+            &BasisAssetMsg::TransferFrom {
+                owner: info.sender.to_string(),
+                recipient: DAO.load(deps.storage)?.to_string(),
+                amount
+                }
+            )?,
+        funds: vec![]
+    };
+    msgs.push(CosmosMsg::Wasm(msg_transfer_from));
+
+    if let Some(msg) = claim_vested(deps.storage, env.clone(), info.sender.clone())? {
+        msgs.push(msg);
+    }
+
+    let now = Uint128::from(env.block.time.seconds());
+    let bond_vesting = BOND_VESTING.load(deps.storage)?;
+    let mut schedule = get_vesting(deps.storage, info.sender.clone())?;
+    schedule.amount = schedule.amount - schedule.claimed + tomb_amount;
+    schedule.period = bond_vesting;
+    schedule.end = now + bond_vesting;
+    schedule.claimed = Uint128::zero();
+    schedule.last_claimed = now;
+    VESTING.save(deps.storage, info.sender, &schedule)?;
+
+    let mut total_vested = TOTAL_VESTED.load(deps.storage)?;
+    total_vested += tomb_amount;
+    TOTAL_VESTED.save(deps.storage, &total_vested)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "bond")
+        .add_attribute("token", token.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("tomb_amount", tomb_amount.to_string())
+        .add_messages(msgs))
+}
+
+// TOMB only goes up for sale once the Treasury has sent it
+pub fn try_top_up(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128
+)
+    -> Result<Response, ContractError>
+{
+    if info.sender != TREASURY.load(deps.storage)? {
+        return Err(ContractError::Unauthorized{});
+    }
+    let tomb_balance = query_token_balance(
+        &deps.querier,
+        TOMB.load(deps.storage)?,
+        env.contract.address
+    )?;
+    let tomb_for_sale = TOMB_FOR_SALE.load(deps.storage)? + amount;
+    if tomb_balance < TOTAL_VESTED.load(deps.storage)? + tomb_for_sale {
+        return Err(ContractError::BondTreasuryError { msg: "top up exceeds the unallocated tomb".to_string() });
+    }
+    TOMB_FOR_SALE.save(deps.storage, &tomb_for_sale)?;
+    Ok(Response::new()
+        .add_attribute("action", "top up")
+        .add_attribute("amount", amount.to_string()))
+}
+
+pub fn try_claim_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+)
+    -> Result<Response, ContractError>
+{
+    let msg = claim_vested(deps.storage, env, info.sender)?;
+    match msg{
+        Some(msg) =>
+            Ok(Response::new()
+            .add_attribute("action", "claim_rewards")
+            .add_message(msg)),
+        None =>
+            Ok(Response::new()
+            .add_attribute("action", "claim_rewards"))
+    }
+}
+
+pub fn try_set_tomb(
+    deps: DepsMut,
+    info: MessageInfo,
+    tomb: Addr
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyowner(deps.storage, info.sender)?;
+    TOMB.save(deps.storage, &tomb)?;
+    Ok(Response::new()
+        .add_attribute("action", "set tomb"))
+}
+
+pub fn try_set_tomb_oracle(
+    deps: DepsMut,
+    info: MessageInfo,
+    tomb_oracle: Addr
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyowner(deps.storage, info.sender)?;
+    TOMB_ORACLE.save(deps.storage, &tomb_oracle)?;
+    Ok(Response::new()
+        .add_attribute("action", "set tomb oracle"))
+}
+
+pub fn try_set_treasury(
+    deps: DepsMut,
+    info: MessageInfo,
+    treasury: Addr
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyowner(deps.storage, info.sender)?;
+    TREASURY.save(deps.storage, &treasury)?;
+    Ok(Response::new()
+        .add_attribute("action", "set treasury"))
+}
+
+pub fn try_set_dao(
+    deps: DepsMut,
+    info: MessageInfo,
+    dao: Addr
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyowner(deps.storage, info.sender)?;
+    DAO.save(deps.storage, &dao)?;
+    Ok(Response::new()
+        .add_attribute("action", "set dao"))
+}
+
+  // Set bonding parameters of token
+pub fn try_set_asset(
+    deps: DepsMut,
+    info: MessageInfo,
+    token: Addr,
+    asset: Asset
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyowner(deps.storage, info.sender)?;
+    if asset.is_added && asset.multiplier == Uint128::zero() {
+        return Err(ContractError::BondTreasuryError { msg: "invalid multiplier".to_string() });
+    }
+    ASSETS.save(deps.storage, token, &asset)?;
+    Ok(Response::new()
+        .add_attribute("action", "set asset"))
+}
+
+// Set bond pricing parameters
+pub fn try_set_bond_parameter(
+    deps: DepsMut,
+    info: MessageInfo,
+    primary_threshold: Uint128,
+    primary_factor: Uint128,
+    second_threshold: Uint128,
+    second_factor: Uint128,
+    vesting_period: Uint128
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyowner(deps.storage, info.sender)?;
+    if second_threshold < primary_threshold {
+        return Err(ContractError::BondTreasuryError { msg: "invalid thresholds".to_string() });
+    }
+    if vesting_period == Uint128::zero() {
+        return Err(ContractError::BondTreasuryError { msg: "invalid vesting period".to_string() });
+    }
+    BOND_THRESHOLD.save(deps.storage, &primary_threshold)?;
+    BOND_FACTOR.save(deps.storage, &primary_factor)?;
+    SECONDARY_THRESHOLD.save(deps.storage, &second_threshold)?;
+    SECONDARY_FACTOR.save(deps.storage, &second_factor)?;
+    BOND_VESTING.save(deps.storage, &vesting_period)?;
+    Ok(Response::new()
+        .add_attribute("action", "set bond parameter"))
+}
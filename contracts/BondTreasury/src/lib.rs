@@ -0,0 +1,9 @@
+pub mod contract;
+pub mod query;
+mod error;
+pub mod state;
+pub mod util;
+pub use crate::error::ContractError;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,56 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Deps, Env, StdResult
+};
+
+use BondTreasury::msg::{QueryMsg};
+use crate::state::{ASSETS, TOTAL_VESTED, TOMB_FOR_SALE, OWNERSHIP};
+use crate::util::{get_tomb_price, get_tomb_return, get_bond_premium,
+    get_token_price, claimable_tomb, get_vesting
+};
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::TotalVested {  } => {
+            to_binary(&TOTAL_VESTED.load(deps.storage)?)
+        },
+
+        QueryMsg::TombForSale {  } => {
+            to_binary(&TOMB_FOR_SALE.load(deps.storage)?)
+        },
+
+        QueryMsg::GetTombReturn { token, amount } => {
+            to_binary(&get_tomb_return(deps.storage, &deps.querier, token, amount)?)
+        },
+
+        QueryMsg::GetBondPremium {  } => {
+            to_binary(&get_bond_premium(deps.storage, &deps.querier)?)
+        },
+
+        QueryMsg::GetTombPrice {  } => {
+            to_binary(&get_tomb_price(deps.storage, &deps.querier)?)
+        },
+
+        QueryMsg::GetTokenPrice { token } => {
+            to_binary(&get_token_price(deps.storage, &deps.querier, token)?)
+        },
+
+        QueryMsg::ClaimableTomb { account } => {
+            to_binary(&claimable_tomb(deps.storage, env, account)?)
+        },
+
+        QueryMsg::GetVesting { account } => {
+            to_binary(&get_vesting(deps.storage, account)?)
+        },
+
+        QueryMsg::GetAsset { token } => {
+            to_binary(&ASSETS.load(deps.storage, token)?)
+        },
+
+        QueryMsg::PendingOperator {  } => {
+            to_binary(&OWNERSHIP.pending_operator(deps.storage)?)
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use super::*;
+use cosmwasm_std::{from_binary, to_binary, Uint128, Addr, CosmosMsg, WasmMsg, OwnedDeps, Env, StdError};
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockStorage, MOCK_CONTRACT_ADDR};
+
+use crate::contract::{execute, instantiate, DAY};
+use crate::query::{query};
+use crate::util::ETHER;
+use BondTreasury::msg::{QueryMsg, ExecuteMsg, InstantiateMsg, VestingSchedule};
+use BasisAsset::msg::{ExecuteMsg as BasisAssetMsg};
+
+use lib::mock_querier::{mock_dependencies, WasmMockQuerier};
+
+fn ether(amount: u128) -> Uint128 {
+    Uint128::from(amount * ETHER)
+}
+
+fn setup(tomb_balance: Uint128) -> OwnedDeps<MockStorage, MockApi, WasmMockQuerier> {
+    let mut deps = mock_dependencies(&[]);
+    deps.querier.with_token_balances(&[(
+        "tomb".to_string(),
+        &[(MOCK_CONTRACT_ADDR.to_string(), tomb_balance)],
+    )]);
+    // TOMB at 1.10, bond token at 2.00
+    deps.querier.with_oracle_prices(&[
+        ("tomb oracle".to_string(), &[("tomb".to_string(), Uint128::from(1_100_000_000_000_000_000u128))]),
+        ("token oracle".to_string(), &[("token".to_string(), ether(2))]),
+    ]);
+
+    let msg = InstantiateMsg {
+        tomb: Addr::unchecked("tomb"),
+        tomb_oracle: Addr::unchecked("tomb oracle"),
+        treasury: Addr::unchecked("treasury"),
+        dao: Addr::unchecked("dao"),
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::SetAsset {
+        token: Addr::unchecked("token"),
+        is_added: true,
+        multiplier: Uint128::from(1_000_000u128),
+        oracle: Addr::unchecked("token oracle"),
+        is_lp: false,
+        pair: Addr::unchecked(""),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::TopUp { amount: tomb_balance };
+    execute(deps.as_mut(), mock_env(), mock_info("treasury", &[]), msg).unwrap();
+    deps
+}
+
+fn env_after(seconds: u64) -> Env {
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(seconds);
+    env
+}
+
+#[test]
+fn workflow(){
+    let mut deps = setup(ether(1_000));
+
+    // 10% over peg: 4% primary premium + 0.45% secondary premium
+    let premium: Uint128 = from_binary(
+        &query(deps.as_ref(), mock_env(), QueryMsg::GetBondPremium {}).unwrap()).unwrap();
+    assert_eq!(premium, Uint128::from(44_500u128));
+
+    let expected = ether(10)
+        .multiply_ratio(ether(2), Uint128::from(1_100_000_000_000_000_000u128))
+        .multiply_ratio(Uint128::from(1_044_500u128), Uint128::from(1_000_000u128));
+    let tomb_return: Uint128 = from_binary(&query(deps.as_ref(), mock_env(),
+        QueryMsg::GetTombReturn { token: Addr::unchecked("token"), amount: ether(10) }).unwrap()).unwrap();
+    assert_eq!(tomb_return, expected);
+
+//bond
+    let msg = ExecuteMsg::Bond { token: Addr::unchecked("token"), amount: ether(10) };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("user1", &[]), msg).unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].msg, CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: "token".to_string(),
+        msg: to_binary(&BasisAssetMsg::TransferFrom {
+            owner: "user1".to_string(),
+            recipient: "dao".to_string(),
+            amount: ether(10),
+        }).unwrap(),
+        funds: vec![],
+    }));
+
+    let total_vested: Uint128 = from_binary(
+        &query(deps.as_ref(), mock_env(), QueryMsg::TotalVested {}).unwrap()).unwrap();
+    assert_eq!(total_vested, expected);
+    let tomb_for_sale: Uint128 = from_binary(
+        &query(deps.as_ref(), mock_env(), QueryMsg::TombForSale {}).unwrap()).unwrap();
+    assert_eq!(tomb_for_sale, ether(1_000) - expected);
+
+    let schedule: VestingSchedule = from_binary(&query(deps.as_ref(), mock_env(),
+        QueryMsg::GetVesting { account: Addr::unchecked("user1") }).unwrap()).unwrap();
+    assert_eq!(schedule.amount, expected);
+    assert_eq!(schedule.period, Uint128::from(3 * DAY));
+
+//claim half way through the vesting period
+    let half_way = env_after((3 * DAY / 2) as u64);
+    let claimable: Uint128 = from_binary(&query(deps.as_ref(), half_way.clone(),
+        QueryMsg::ClaimableTomb { account: Addr::unchecked("user1") }).unwrap()).unwrap();
+    assert_eq!(claimable, expected.multiply_ratio(1u128, 2u128));
+
+    let res = execute(deps.as_mut(), half_way.clone(), mock_info("user1", &[]), ExecuteMsg::ClaimRewards {}).unwrap();
+    assert_eq!(res.messages[0].msg, CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: "tomb".to_string(),
+        msg: to_binary(&BasisAssetMsg::Transfer {
+            recipient: "user1".to_string(),
+            amount: claimable,
+        }).unwrap(),
+        funds: vec![],
+    }));
+
+    let total_vested: Uint128 = from_binary(
+        &query(deps.as_ref(), half_way.clone(), QueryMsg::TotalVested {}).unwrap()).unwrap();
+    assert_eq!(total_vested, expected - claimable);
+
+    let claimable: Uint128 = from_binary(&query(deps.as_ref(), half_way,
+        QueryMsg::ClaimableTomb { account: Addr::unchecked("user1") }).unwrap()).unwrap();
+    assert_eq!(claimable, Uint128::zero());
+
+//claim the rest once fully vested
+    let res = execute(deps.as_mut(), env_after((4 * DAY) as u64), mock_info("user1", &[]),
+        ExecuteMsg::ClaimRewards {}).unwrap();
+    assert_eq!(res.messages.len(), 1);
+    let total_vested: Uint128 = from_binary(
+        &query(deps.as_ref(), mock_env(), QueryMsg::TotalVested {}).unwrap()).unwrap();
+    assert_eq!(total_vested, Uint128::zero());
+}
+
+#[test]
+fn uneven_claims_pay_out_the_whole_schedule(){
+    let mut deps = setup(ether(1_000));
+    let msg = ExecuteMsg::Bond { token: Addr::unchecked("token"), amount: ether(7) };
+    execute(deps.as_mut(), mock_env(), mock_info("user1", &[]), msg).unwrap();
+    let schedule: VestingSchedule = from_binary(&query(deps.as_ref(), mock_env(),
+        QueryMsg::GetVesting { account: Addr::unchecked("user1") }).unwrap()).unwrap();
+
+    // each claim rounds down, the last one pays what they left behind
+    let mut claimed = Uint128::zero();
+    for seconds in [100_001u64, 200_003, 259_199, 300_000] {
+        let env = env_after(seconds);
+        let claimable: Uint128 = from_binary(&query(deps.as_ref(), env.clone(),
+            QueryMsg::ClaimableTomb { account: Addr::unchecked("user1") }).unwrap()).unwrap();
+        execute(deps.as_mut(), env, mock_info("user1", &[]), ExecuteMsg::ClaimRewards {}).unwrap();
+        claimed += claimable;
+    }
+    assert_eq!(claimed, schedule.amount);
+    let total_vested: Uint128 = from_binary(
+        &query(deps.as_ref(), mock_env(), QueryMsg::TotalVested {}).unwrap()).unwrap();
+    assert_eq!(total_vested, Uint128::zero());
+}
+
+#[test]
+fn bond_rejects_unknown_asset_and_empty_treasury(){
+    let mut deps = setup(ether(1));
+
+    let msg = ExecuteMsg::Bond { token: Addr::unchecked("other"), amount: ether(10) };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("user1", &[]), msg).unwrap_err();
+    assert!(matches!(err, ContractError::BondTreasuryError { .. }));
+
+    let msg = ExecuteMsg::Bond { token: Addr::unchecked("token"), amount: ether(10) };
+    match execute(deps.as_mut(), mock_env(), mock_info("user1", &[]), msg) {
+        Err(ContractError::BondTreasuryError { msg }) => assert_eq!(msg, "insufficient tomb for sale"),
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn only_the_treasury_tops_up_tomb_it_sent(){
+    let mut deps = setup(ether(1_000));
+
+    let msg = ExecuteMsg::TopUp { amount: ether(1) };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("user1", &[]), msg).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // the whole balance is already for sale
+    let msg = ExecuteMsg::TopUp { amount: ether(1) };
+    match execute(deps.as_mut(), mock_env(), mock_info("treasury", &[]), msg) {
+        Err(ContractError::BondTreasuryError { msg }) => assert_eq!(msg, "top up exceeds the unallocated tomb"),
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn zero_tomb_price_is_an_error(){
+    let mut deps = setup(ether(1_000));
+    deps.querier.with_oracle_prices(&[
+        ("tomb oracle".to_string(), &[("tomb".to_string(), Uint128::zero())]),
+        ("token oracle".to_string(), &[("token".to_string(), ether(2))]),
+    ]);
+
+    let err = query(deps.as_ref(), mock_env(),
+        QueryMsg::GetTombReturn { token: Addr::unchecked("token"), amount: ether(10) }).unwrap_err();
+    assert_eq!(err, StdError::generic_err("tomb price is zero"));
+
+    let msg = ExecuteMsg::Bond { token: Addr::unchecked("token"), amount: ether(10) };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("user1", &[]), msg).unwrap_err();
+    assert!(matches!(err, ContractError::Std(_)));
+}
+
+#[test]
+fn owner_only_setters(){
+    let mut deps = setup(ether(1));
+
+    let msg = ExecuteMsg::SetTreasury { treasury: Addr::unchecked("user1") };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("user1", &[]), msg).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let msg = ExecuteMsg::SetBondParameter {
+        primary_threshold: Uint128::from(50_000u128),
+        primary_factor: Uint128::from(500_000u128),
+        second_threshold: Uint128::from(10_000u128),
+        second_factor: Uint128::from(100_000u128),
+        vesting_period: Uint128::from(DAY),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap_err();
+    assert!(matches!(err, ContractError::BondTreasuryError { .. }));
+}
@@ -0,0 +1,211 @@
+use crate::error::ContractError;
+
+use cosmwasm_std::{ Storage, Uint128, Addr, StdResult, StdError, Response, Env, QuerierWrapper,
+    WasmMsg, to_binary, CosmosMsg};
+use crate::state::{
+    OWNER, TOMB, TOMB_ORACLE, ASSETS, VESTING, BOND_THRESHOLD,
+    BOND_FACTOR, SECONDARY_THRESHOLD, SECONDARY_FACTOR, TOTAL_VESTED
+};
+use crate::contract::{DENOMINATOR};
+use BasisAsset::msg::{ExecuteMsg as BasisAssetMsg};
+use BondTreasury::msg::{VestingSchedule};
+use Oracle::msg::{QueryMsg as OracleQuery};
+
+use terraswap::asset::{AssetInfo};
+use terraswap::pair::{QueryMsg as PairQueryMsg, PoolResponse};
+
+pub const ETHER: u128 = 1_000_000_000_000_000_000u128;
+
+pub fn check_onlyowner(storage: &dyn Storage, sender: Addr) -> Result<Response, ContractError> {
+    let owner = OWNER.may_load(storage)?;
+    if owner != Some(sender) {
+        return Err(ContractError::Unauthorized{});
+    }
+    Ok(Response::new())
+}
+
+pub fn check_only_asset(storage: &dyn Storage, token: Addr) -> Result<Response, ContractError>  {
+    let asset = ASSETS.may_load(storage, token)?;
+    match asset {
+        Some(asset) if asset.is_added => Ok(Response::new()),
+        _ => Err(ContractError::BondTreasuryError { msg: "token is not a bondable asset".to_string() })
+    }
+}
+
+pub fn get_vesting(storage: &dyn Storage, account: Addr) -> StdResult<VestingSchedule> {
+    let schedule = VESTING.may_load(storage, account)?;
+    Ok(schedule.unwrap_or(VestingSchedule {
+        amount: Uint128::zero(),
+        period: Uint128::zero(),
+        end: Uint128::zero(),
+        claimed: Uint128::zero(),
+        last_claimed: Uint128::zero()
+    }))
+}
+
+// Amount of the schedule that has vested since the last claim, the rest of it once fully
+// vested so the rounding of earlier claims is not left behind
+pub fn vested_since_last_claim(schedule: &VestingSchedule, current_time: Uint128) -> Uint128 {
+    if schedule.amount <= schedule.claimed {
+        return Uint128::zero();
+    }
+    if current_time >= schedule.end {
+        return schedule.amount - schedule.claimed;
+    }
+    if current_time <= schedule.last_claimed {
+        return Uint128::zero();
+    }
+    schedule.amount.multiply_ratio(current_time - schedule.last_claimed, schedule.period)
+}
+
+pub fn claim_vested(
+    storage: &mut dyn Storage,
+    env: Env,
+    account: Addr
+)
+    -> StdResult<Option<CosmosMsg>>
+{
+    let mut schedule = get_vesting(storage, account.clone())?;
+    let current_time = Uint128::from(env.block.time.seconds());
+
+    let claimable = vested_since_last_claim(&schedule, current_time);
+    if claimable == Uint128::zero() {
+        return Ok(None);
+    }
+
+    schedule.claimed += claimable;
+    if current_time > schedule.end {
+        schedule.last_claimed = schedule.end;
+    } else {
+        schedule.last_claimed = current_time;
+    }
+    VESTING.save(storage, account.clone(), &schedule)?;
+
+    let mut total_vested = TOTAL_VESTED.load(storage)?;
+    total_vested -= claimable;
+    TOTAL_VESTED.save(storage, &total_vested)?;
+
+    let msg_transfer = WasmMsg::Execute {
+        contract_addr: TOMB.load(storage)?.to_string(),
+        msg: to_binary(
+            &BasisAssetMsg::Transfer {
+                recipient: account.to_string(),
+                amount: claimable
+                }
+            )?,
+        funds: vec![]
+    };
+    Ok(Some(CosmosMsg::Wasm(msg_transfer)))
+}
+
+// Get claimable vested Tomb for account
+pub fn claimable_tomb(storage: &dyn Storage, env: Env, account: Addr)
+    -> StdResult<Uint128>
+{
+    let schedule = get_vesting(storage, account)?;
+    let current_time = Uint128::from(env.block.time.seconds());
+    Ok(vested_since_last_claim(&schedule, current_time))
+}
+
+pub fn get_tomb_return(storage: &dyn Storage, querier: &QuerierWrapper, token: Addr, amount: Uint128) -> StdResult<Uint128> {
+    let asset = match ASSETS.may_load(storage, token.clone())? {
+        Some(asset) if asset.is_added => asset,
+        _ => return Err(StdError::generic_err("token is not a bondable asset"))
+    };
+    let tomb_price = get_tomb_price(storage, querier)?;
+    if tomb_price.is_zero() {
+        return Err(StdError::generic_err("tomb price is zero"));
+    }
+    let token_price = get_token_price(storage, querier, token)?;
+    let bond_premium = get_bond_premium(storage, querier)?;
+    let denominator = Uint128::from(DENOMINATOR);
+
+    let res = amount
+        .multiply_ratio(token_price, tomb_price)
+        .multiply_ratio(bond_premium + denominator, denominator)
+        .multiply_ratio(asset.multiplier, denominator);
+
+    Ok(res)
+}
+
+pub fn get_bond_premium(storage: &dyn Storage, querier: &QuerierWrapper)
+    -> StdResult<Uint128>
+{
+    let tomb_price = get_tomb_price(storage, querier)?;
+    if tomb_price < Uint128::from(ETHER) {
+        return Ok(Uint128::zero());
+    }
+    let denominator = Uint128::from(DENOMINATOR);
+
+    let tomb_premium = tomb_price * denominator / Uint128::from(ETHER) - denominator;
+
+    let bond_threshold = BOND_THRESHOLD.load(storage)?;
+    let bond_factor = BOND_FACTOR.load(storage)?;
+
+    if tomb_premium < bond_threshold {
+        return Ok(Uint128::zero());
+    }
+
+    let secondary_threshold = SECONDARY_THRESHOLD.load(storage)?;
+    if tomb_premium <= secondary_threshold {
+        Ok((tomb_premium - bond_threshold) * bond_factor / denominator)
+    } else {
+        let secondary_factor = SECONDARY_FACTOR.load(storage)?;
+
+        let primary_premium = (secondary_threshold - bond_threshold) * bond_factor / denominator;
+        Ok(primary_premium + (tomb_premium - secondary_threshold) * secondary_factor / denominator)
+    }
+}
+
+pub fn oracle_consult(querier: &QuerierWrapper, oracle: Addr, token: AssetInfo, amount: Uint128) -> StdResult<Uint128> {
+    let price: Uint128 = querier.query_wasm_smart(
+        oracle,
+        &OracleQuery::Consult {
+                token,
+                amount_in: amount
+            }
+    )?;
+
+    Ok(price)
+}
+
+// oracle
+pub fn get_tomb_price(storage: &dyn Storage, querier: &QuerierWrapper) -> StdResult<Uint128> {
+    let tomb = TOMB.load(storage)?;
+
+    let tomb_asset = AssetInfo::Token { contract_addr: tomb.to_string()};
+    oracle_consult(querier, TOMB_ORACLE.load(storage)?, tomb_asset, Uint128::from(ETHER))
+}
+
+// Price of one unit (1e18) of a bond asset. LP tokens are valued from the pair reserves,
+// the native side of the pair being the quote asset the oracles are denominated in.
+pub fn get_token_price(storage: &dyn Storage, querier: &QuerierWrapper, token: Addr)
+    -> StdResult<Uint128>
+{
+    let asset = match ASSETS.may_load(storage, token.clone())? {
+        Some(asset) if asset.is_added => asset,
+        _ => return Err(StdError::generic_err("token is not a bondable asset"))
+    };
+    if !asset.is_lp {
+        let token_asset = AssetInfo::Token { contract_addr: token.to_string() };
+        return oracle_consult(querier, asset.oracle, token_asset, Uint128::from(ETHER));
+    }
+
+    let pool: PoolResponse = querier.query_wasm_smart(
+        asset.pair,
+        &PairQueryMsg::Pool{}
+    )?;
+    if pool.total_share == Uint128::zero() {
+        return Ok(Uint128::zero());
+    }
+
+    let (token_side, quote_side) = if pool.assets[0].info.is_native_token() {
+        (&pool.assets[1], &pool.assets[0])
+    } else {
+        (&pool.assets[0], &pool.assets[1])
+    };
+    let token_price = oracle_consult(querier, asset.oracle, token_side.info.clone(), Uint128::from(ETHER))?;
+    let pool_value = token_side.amount.multiply_ratio(token_price, Uint128::from(ETHER)) + quote_side.amount;
+
+    Ok(pool_value.multiply_ratio(Uint128::from(ETHER), pool.total_share))
+}
@@ -5,9 +5,11 @@ use cosmwasm_std::{
 use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
 use std::convert::TryInto;
 use cw20::{Cw20ExecuteMsg};
+use cw2::{set_contract_version, CONTRACT};
+use lib::migrate::migrate_version;
 
 use crate::error::ContractError;
-use crate::msg::{AllowanceResponse, BalanceResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::msg::{AllowanceResponse, BalanceResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use crate::state::{Constants, startTime, endTime, communityFundRewardRate, devFundRewardRate,
     communityFund, devFund, communityFundLastClaimed, devFundLastClaimed, rewardPoolDistributed,
 };
@@ -19,6 +21,10 @@ pub const PREFIX_ALLOWANCES: &[u8] = b"allowances";
 pub const KEY_CONSTANTS: &[u8] = b"constants";
 pub const KEY_TOTAL_SUPPLY: &[u8] = b"total_supply";
 
+// version info for migration info
+const CONTRACT_NAME: &str = "ERC20";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 #[entry_point]
 pub fn instantiate(
     deps: DepsMut,
@@ -27,6 +33,8 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> 
 {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     let mut config_store = PrefixedStorage::new(deps.storage, PREFIX_CONFIG);
 
     let ether: Uint128 = Uint128::from((10 as u128).pow(18 as u32));
@@ -82,6 +90,25 @@ pub fn instantiate(
     Ok(Response::default())
 }
 
+#[entry_point]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    // the first deployments did not record any cw2 info, only trust that from a token
+    // holding ERC20 constants, anything else fails below on its missing contract info
+    let is_legacy_token = ReadonlyPrefixedStorage::new(deps.storage, PREFIX_CONFIG).get(KEY_CONSTANTS).is_some();
+    if CONTRACT.may_load(deps.storage)?.is_none() && is_legacy_token {
+        set_contract_version(deps.storage, CONTRACT_NAME, "0.0.0")?;
+    }
+    let previous = migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[])?;
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", previous.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
 #[entry_point]
 pub fn execute(
     deps: DepsMut,
@@ -1,11 +1,15 @@
 use cosmwasm_std::StdError;
 use thiserror::Error;
+use lib::migrate::MigrateError;
 
 #[derive(Error, Debug)]
 pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Migrate(#[from] MigrateError),
+
     #[error("Name is not in the expected format (3-30 UTF-8 bytes)")]
     NameWrongFormat {},
 
@@ -16,6 +16,9 @@ pub struct InstantiateMsg {
     pub _devFund: String
 }
 
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct MigrateMsg {}
+
 #[derive(Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
@@ -5,20 +5,25 @@ use cosmwasm_std::{
     Uint128, CosmosMsg, StdResult, StdError, QuerierWrapper
 };
 
-use IMasonry::msg::{ExecuteMsg, InstantiateMsg, MasonrySnapshot};
+use IMasonry::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, MasonrySnapshot, Operation};
 use Treasury::msg::{QueryMsg as TreasuryQuery};
 use cw2::set_contract_version;
+use lib::migrate::{migrate_version, Version};
 
 use crate::error::ContractError;
-use crate::state::{OPERATOR, TOMB, SHARE, TOTALSUPPLY, INITIALIZED, BALANCES,
-    TREASURY, MASONS, MASONRY_HISTORY, WITHDRAW_LOCKUP_EPOCHS, REWARD_LOCKUP_EPOCHS};
+use crate::state::{OPERATOR, OWNERSHIP, TOMB, SHARE, TOTALSUPPLY, INITIALIZED, BALANCES,
+    TREASURY, MASONS, LEGACY_MASONRY_HISTORY, WITHDRAW_LOCKUP_EPOCHS, REWARD_LOCKUP_EPOCHS, PAUSE};
+use crate::state::{LATEST_SNAPSHOT_INDEX, LEGACY_SNAPSHOTS_LEFT};
 use crate::util::{balance_of, check_onlyoperator, check_not_initialized, check_mason_exists,
-    safe_share_transferfrom, safe_tomb_transferfrom, safe_transferfrom, update_reward, 
-    get_latest_snapshot, check_onlyoneblock};
+    safe_share_transferfrom, safe_tomb_transferfrom, safe_transfer, safe_share_transfer,
+    safe_tomb_transfer, update_reward, get_latest_snapshot, push_snapshot, check_onlyoneblock};
 // version info for migration info
 const CONTRACT_NAME: &str = "Masonry";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// snapshots moved by one migration unless the MigrateMsg says otherwise
+const DEFAULT_SNAPSHOT_LIMIT: u32 = 1_000;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -33,6 +38,57 @@ pub fn instantiate(
         .add_attribute("method", "instantiate"))
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    let previous = migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[])?;
+
+    let mut res = Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", previous.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION);
+
+    // a batched history migration continues under the new version
+    if previous < Version::new(0, 0, 2) || LEGACY_SNAPSHOTS_LEFT.may_load(deps.storage)?.is_some() {
+        let limit = msg.snapshot_limit.unwrap_or(DEFAULT_SNAPSHOT_LIMIT) as usize;
+        let (migrated, left) = migrate_masonry_history(deps.storage, limit)?;
+        res = res
+            .add_attribute("migrated_snapshots", migrated.to_string())
+            .add_attribute("snapshots_left", left.to_string());
+    }
+    Ok(res)
+}
+// 0.0.2: the snapshot history moves from a single Vec Item into a Map indexed by snapshot index,
+// `limit` snapshots at a time so a long history fits in the gas of a few migrations.
+// Returns the snapshots moved and those left, the Masonry refuses executions until none are.
+pub fn migrate_masonry_history(storage: &mut dyn Storage, limit: usize) -> StdResult<(usize, usize)> {
+    let masonry_history = match LEGACY_MASONRY_HISTORY.may_load(storage)? {
+        Some(masonry_history) => masonry_history,
+        None => return Ok((0, 0)) // never initialized, nothing to move
+    };
+    // the Map is filled in order, its latest index tells where the previous batch stopped
+    let start = match LATEST_SNAPSHOT_INDEX.may_load(storage)? {
+        Some(latest) => latest.u128() as usize + 1,
+        None => 0
+    };
+    let end = std::cmp::min(start + limit, masonry_history.len());
+    for snapshot in masonry_history[start..end].iter() {
+        push_snapshot(storage, snapshot)?;
+    }
+
+    let left = masonry_history.len() - end;
+    if left == 0 {
+        LEGACY_MASONRY_HISTORY.remove(storage);
+        LEGACY_SNAPSHOTS_LEFT.remove(storage);
+    } else {
+        LEGACY_SNAPSHOTS_LEFT.save(storage, &Uint128::from(left as u128))?;
+    }
+    Ok((end - start, left))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -40,12 +96,34 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    // snapshots still in the legacy history cannot be read
+    if LEGACY_SNAPSHOTS_LEFT.may_load(deps.storage)?.is_some() {
+        return Err(ContractError::HistoryMigrationInProgress{});
+    }
     match msg {
         ExecuteMsg::Initialize{ tomb, share, treasury }
             => try_initialize(deps, env, info, tomb, share, treasury ),
         
-        ExecuteMsg::SetOperator{ operator }
-            => try_setoperator(deps, info, operator),
+        ExecuteMsg::ProposeOperator{ operator }
+            => Ok(OWNERSHIP.propose(deps.storage, &info.sender, operator)?),
+
+        ExecuteMsg::AcceptOperator{ }
+            => Ok(OWNERSHIP.accept(deps.storage, &info.sender)?),
+
+        ExecuteMsg::CancelProposal{ }
+            => Ok(OWNERSHIP.cancel(deps.storage, &info.sender)?),
+
+        ExecuteMsg::RenounceOperator{ }
+            => Ok(OWNERSHIP.renounce(deps.storage, &info.sender)?),
+
+        ExecuteMsg::SetGuardian{ guardian }
+            => try_set_guardian(deps, info, guardian),
+
+        ExecuteMsg::Pause{ operations }
+            => Ok(PAUSE.pause(deps.storage, &info.sender, operations)?),
+
+        ExecuteMsg::Unpause{ operations }
+            => Ok(PAUSE.unpause(deps.storage, &info.sender, operations)?),
         
         ExecuteMsg::SetLockUp{ withdraw_lockup_epochs,reward_lockup_epochs }
             => try_setlockup(deps, env, info, withdraw_lockup_epochs, reward_lockup_epochs),
@@ -84,15 +162,14 @@ pub fn try_initialize(
     TOMB.save(deps.storage, &tomb)?;
     SHARE.save(deps.storage, &share)?;
     TREASURY.save(deps.storage, &treasury)?;
+    TOTALSUPPLY.save(deps.storage, &Uint128::zero())?;
 
     let genesis_snapshot: MasonrySnapshot = MasonrySnapshot{
         time : Uint128::from(env.block.time.seconds()), 
         reward_received : Uint128::zero(), 
         reward_per_share : Uint128::zero()
     };
-    let mut masonry_history: Vec<MasonrySnapshot> = Vec::new();
-    masonry_history.push(genesis_snapshot);
-    MASONRY_HISTORY.save(deps.storage, &masonry_history)?;
+    push_snapshot(deps.storage, &genesis_snapshot)?;
 
     WITHDRAW_LOCKUP_EPOCHS.save(deps.storage, &Uint128::from(3u128))?;// Lock for 6 epochs (36h) before release withdraw
     REWARD_LOCKUP_EPOCHS.save(deps.storage, &Uint128::from(10u128))?; // Lock for 3 epochs (18h) before release claimReward
@@ -103,18 +180,15 @@ pub fn try_initialize(
     Ok(Response::new()
         .add_attribute("action", "initialize"))
 }
-pub fn try_setoperator(
+pub fn try_set_guardian(
     deps: DepsMut,
     info: MessageInfo,
-    operator: Addr,
+    guardian: Option<Addr>,
 )
     -> Result<Response, ContractError>
 {
     check_onlyoperator(deps.storage, info.sender)?;
-
-    OPERATOR.save(deps.storage, &operator)?;
-    Ok(Response::new()
-        .add_attribute("action", "set operator"))
+    Ok(PAUSE.set_guardian(deps.storage, guardian)?)
 }
 
 pub fn try_setlockup(
@@ -151,7 +225,7 @@ pub fn _stake(
     total_supply += amount;
     TOTALSUPPLY.save(storage, &total_supply)?;
 
-    let mut balance = BALANCES.load(storage, sender.clone())?;
+    let mut balance = balance_of(storage, sender.clone());
     balance += amount;
     BALANCES.save(storage, sender.clone(), &balance)?;
     
@@ -181,7 +255,7 @@ pub fn _withdraw(
     mason_share -= amount;
     BALANCES.save(storage, sender.clone(), &mason_share)?;
     
-    safe_share_transferfrom(storage, querier, env.contract.address,sender,  amount)
+    safe_share_transfer(storage, querier, env.contract.address, sender, amount)
 }
 
 pub fn try_stake(
@@ -192,6 +266,7 @@ pub fn try_stake(
 )
     ->Result<Response, ContractError>
 {
+    PAUSE.assert_not_paused(deps.storage, &Operation::Stake)?;
     let sender = info.sender;
     check_onlyoneblock(deps.storage, Uint128::from(env.block.height as u128), sender.clone())?;
     update_reward(deps.storage, sender.clone())?;
@@ -218,11 +293,19 @@ pub fn try_withdraw(
 )
     ->Result<Response, ContractError>
 {
-    let _info = info.clone();
-    let _env = env.clone();
-    
-    let sender = info.sender;
+    PAUSE.assert_not_paused(deps.storage, &Operation::Withdraw)?;
+    withdraw(deps, env, info.sender, amount)
+}
 
+// shared with Exit, which stays available while paused. Rewards stay earned while claims are paused
+fn withdraw(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    amount: Uint128
+)
+    ->Result<Response, ContractError>
+{
     check_onlyoneblock(deps.storage, Uint128::from(env.block.height as u128), sender.clone())?;
     check_mason_exists(deps.storage, sender.clone())?;
     update_reward(deps.storage, sender.clone())?;
@@ -239,12 +322,15 @@ pub fn try_withdraw(
         return Err(ContractError::StillInLockup {  });    
     }
     
-    let mut _deps = deps;
-    execute(_deps.branch(), env, _info, ExecuteMsg::ClaimReward {  })?;
-
-    let msg = _withdraw(_deps.storage, &_deps.querier, _env, sender, amount)?;
+    let mut msgs: Vec<CosmosMsg> = Vec::new();
+    if !PAUSE.is_paused(deps.storage, &Operation::ClaimReward)? {
+        if let Some(msg) = _claim_reward(deps.storage, &deps.querier, env.clone(), sender.clone())? {
+            msgs.push(msg);
+        }
+    }
+    msgs.push(_withdraw(deps.storage, &deps.querier, env, sender, amount)?);
     Ok(Response::new()
-        .add_message(msg))
+        .add_messages(msgs))
 }
 
 pub fn try_exit(
@@ -254,39 +340,53 @@ pub fn try_exit(
 )
     ->Result<Response, ContractError>
 {
-    let _info = info.clone();
-    let balance = balance_of(deps.storage, info.sender);
-    try_withdraw(deps, env, _info, balance)
+    let balance = balance_of(deps.storage, info.sender.clone());
+    withdraw(deps, env, info.sender, balance)
 }
 
-pub fn try_claimreward(
-    deps: DepsMut,
+pub fn _claim_reward(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper,
     env: Env,
-    info: MessageInfo,
+    sender: Addr
 )
-    ->Result<Response, ContractError>
+    -> Result<Option<CosmosMsg>, ContractError>
 {
-    let sender = info.sender;
-    update_reward(deps.storage, sender.clone())?;
-    let mut mason = MASONS.load(deps.storage, sender.clone())?;
+    update_reward(storage, sender.clone())?;
+    let mut mason = MASONS.load(storage, sender.clone())?;
 
     let reward = mason.reward_earned;
     if reward > Uint128::zero() {
-        let epoch: Uint128 = deps.querier.query_wasm_smart(
-            TREASURY.load(deps.storage)?, &TreasuryQuery::Epoch {  })?;
-        let reward_lockup_epochs = REWARD_LOCKUP_EPOCHS.load(deps.storage)?;
+        let epoch: Uint128 = querier.query_wasm_smart(
+            TREASURY.load(storage)?, &TreasuryQuery::Epoch {  })?;
+        let reward_lockup_epochs = REWARD_LOCKUP_EPOCHS.load(storage)?;
 
         if mason.epoch_timer_start + reward_lockup_epochs > epoch {
             return Err(ContractError::StillInLockup {  });
         }
         mason.epoch_timer_start = epoch;
         mason.reward_earned = Uint128::zero();
+        MASONS.save(storage, sender.clone(), &mason)?;
 
-        let msg = safe_tomb_transferfrom(deps.storage, &deps.querier, env.contract.address, sender, reward)?;
-        return Ok(Response::new()
-            .add_message(msg));
+        let msg = safe_tomb_transfer(storage, querier, env.contract.address, sender, reward)?;
+        return Ok(Some(msg));
+    }
+    Ok(None)
+}
+
+pub fn try_claimreward(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+)
+    ->Result<Response, ContractError>
+{
+    PAUSE.assert_not_paused(deps.storage, &Operation::ClaimReward)?;
+    match _claim_reward(deps.storage, &deps.querier, env, info.sender)? {
+        Some(msg) => Ok(Response::new()
+            .add_message(msg)),
+        None => Ok(Response::new())
     }
-    Ok(Response::new())
 }
 
 pub fn try_allocate_seigniorage(
@@ -309,7 +409,7 @@ pub fn try_allocate_seigniorage(
     }
 
     let prev_rps = get_latest_snapshot(deps.storage).reward_per_share;
-    let next_rps = prev_rps + (amount * Uint128::from((10u64).pow(18u32)) / total_supply);
+    let next_rps = prev_rps + amount.multiply_ratio((10u64).pow(18u32), total_supply);
     // Create & add new snapshot
 
     let new_snapshot: MasonrySnapshot = MasonrySnapshot{
@@ -317,9 +417,7 @@ pub fn try_allocate_seigniorage(
         reward_received: amount,
         reward_per_share: next_rps
     };
-    let mut masonry_history = MASONRY_HISTORY.load(deps.storage)?;
-    masonry_history.push(new_snapshot);
-    MASONRY_HISTORY.save(deps.storage, &masonry_history)?;
+    push_snapshot(deps.storage, &new_snapshot)?;
 
     let msg = safe_tomb_transferfrom(deps.storage, &deps.querier, sender, env.contract.address, amount)?;
     Ok(Response::new()
@@ -344,7 +442,7 @@ pub fn try_governance_recover_unsupported(
         return Err(ContractError::InvalidToken{ })
     }
 
-    let msg = safe_transferfrom(deps.storage, &deps.querier, token, env.contract.address, to, amount)?;
+    let msg = safe_transfer(&deps.querier, token, env.contract.address, to, amount)?;
     Ok(Response::new()
         .add_message(msg))
 }
\ No newline at end of file
@@ -1,11 +1,23 @@
 use cosmwasm_std::StdError;
 use thiserror::Error;
+use lib::migrate::MigrateError;
+use lib::operator::OwnershipError;
+use lib::pause::PauseError;
 
 #[derive(Error, Debug)]
 pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Migrate(#[from] MigrateError),
+
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
+    #[error("{0}")]
+    Pause(#[from] PauseError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -37,5 +49,8 @@ pub enum ContractError {
     StillInLockup{},
 
     #[error("ContractGuard: one block, one function")]
-    ContractGuard{}
+    ContractGuard{},
+
+    #[error("Masonry: history migration in progress")]
+    HistoryMigrationInProgress{}
 }
@@ -3,6 +3,7 @@ pub mod query;
 mod error;
 pub mod state;
 pub mod util;
+pub use crate::error::ContractError;
 
 #[cfg(test)]
 mod test;
@@ -7,7 +7,8 @@ use cosmwasm_std::{
 
 use IMasonry::msg::{QueryMsg, Masonseat};
 use crate::state::{OPERATOR, TOMB, SHARE, TOTALSUPPLY, INITIALIZED, BALANCES,
-    TREASURY, MASONS, MASONRY_HISTORY, WITHDRAW_LOCKUP_EPOCHS, REWARD_LOCKUP_EPOCHS};
+    TREASURY, MASONS, WITHDRAW_LOCKUP_EPOCHS, REWARD_LOCKUP_EPOCHS};
+use crate::state::{OWNERSHIP, PAUSE};
 use crate::util::{get_latest_snapshot, latest_snapshot_index, balance_of, earned};
 use Treasury::msg::{QueryMsg as TreasuryQuery};
 
@@ -17,6 +18,12 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Operator{ } =>{
             to_binary(&OPERATOR.load(deps.storage)?)
         }
+        QueryMsg::PendingOperator{ } =>{
+            to_binary(&OWNERSHIP.pending_operator(deps.storage)?)
+        }
+        QueryMsg::PauseStatus{ } =>{
+            to_binary(&PAUSE.status(deps.storage)?)
+        }
 
         QueryMsg::LatestSnapshotIndex{ } => {
             to_binary(&latest_snapshot_index(deps.storage)?)
@@ -1,6 +1,8 @@
 use cosmwasm_std::{Addr, Uint128};
 use cw_storage_plus::{Item, Map, U128Key};
-use IMasonry::msg::{Masonseat, MasonrySnapshot};
+use lib::operator::Ownership;
+use lib::pause::Pause;
+use IMasonry::msg::{Masonseat, MasonrySnapshot, Operation};
 
 //---ContractGuard-----------------------------
 // pub const status: Map<(U128Key, Addr), bool> = Map::new("contract guard status");
@@ -15,11 +17,24 @@ pub const STATUS: Map<(U128Key, Addr), bool> = Map::new("Contract guard status")
 
 //---Masonry-------------------------------------
 pub const OPERATOR: Item<Addr> = Item::new("operator");
+pub const PENDING_OPERATOR: Item<Addr> = Item::new("pending operator");
+pub const OWNERSHIP: Ownership = Ownership::new(&OPERATOR, &PENDING_OPERATOR);
 pub const INITIALIZED: Item<bool> = Item::new("initialized");
 pub const TOMB: Item<Addr> = Item::new("tomb");
 pub const TREASURY: Item<Addr> = Item::new("treasury");
 
 pub const MASONS: Map<Addr, Masonseat> = Map::new("masons");
-pub const MASONRY_HISTORY: Item<Vec<MasonrySnapshot>> = Item::new("masonry_history");
+pub const MASONRY_HISTORY: Map<U128Key, MasonrySnapshot> = Map::new("masonry history");
+pub const LATEST_SNAPSHOT_INDEX: Item<Uint128> = Item::new("latest snapshot index");
 pub const WITHDRAW_LOCKUP_EPOCHS: Item<Uint128> = Item::new("withdraw_lockup_epochs");
 pub const REWARD_LOCKUP_EPOCHS: Item<Uint128> = Item::new("reward_lockup_epochs");
+
+pub const GUARDIAN: Item<Addr> = Item::new("guardian");
+pub const PAUSED: Item<Vec<Operation>> = Item::new("paused");
+pub const PAUSE: Pause<Operation> = Pause::new(&GUARDIAN, &PAUSED);
+
+//---Legacy--------------------------------------
+// 0.0.1 kept the whole history in a single Item, migrated into MASONRY_HISTORY
+pub const LEGACY_MASONRY_HISTORY: Item<Vec<MasonrySnapshot>> = Item::new("masonry_history");
+// snapshots still to move while the history is migrated in batches
+pub const LEGACY_SNAPSHOTS_LEFT: Item<Uint128> = Item::new("legacy snapshots left");
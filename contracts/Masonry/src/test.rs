@@ -14,3 +14,87 @@ fn workflow(){
 
 }
 
+
+#[test]
+fn migrate_moves_history_into_map(){
+    use crate::contract::migrate;
+    use crate::state::LEGACY_MASONRY_HISTORY;
+    use crate::util::{latest_snapshot_index, get_snapshot, get_latest_snapshot};
+    use IMasonry::msg::{MigrateMsg, MasonrySnapshot};
+    use lib::migrate::MigrateError;
+
+    let mut deps = mock_dependencies(&[]);
+    cw2::set_contract_version(&mut deps.storage, "Masonry", "0.0.1").unwrap();
+
+    let history: Vec<MasonrySnapshot> = (0..3u128).map(|i| MasonrySnapshot{
+        time: Uint128::from(i),
+        reward_received: Uint128::from(i * 10),
+        reward_per_share: Uint128::from(i * 100)
+    }).collect();
+    LEGACY_MASONRY_HISTORY.save(&mut deps.storage, &history).unwrap();
+
+    let res = migrate(deps.as_mut(), mock_env(), MigrateMsg{ snapshot_limit: None }).unwrap();
+    assert!(res.attributes.iter().any(|attr| attr.key == "migrated_snapshots" && attr.value == "3"));
+
+    assert_eq!(latest_snapshot_index(&deps.storage).unwrap(), Uint128::from(2u128));
+    assert_eq!(get_snapshot(&deps.storage, Uint128::from(1u128)).unwrap(), history[1]);
+    assert_eq!(get_latest_snapshot(&deps.storage), history[2]);
+    assert_eq!(LEGACY_MASONRY_HISTORY.may_load(&deps.storage).unwrap(), None);
+
+    let version = cw2::get_contract_version(&deps.storage).unwrap();
+    assert_eq!(version.version, env!("CARGO_PKG_VERSION"));
+
+    // refuses to go back to an older code
+    cw2::set_contract_version(&mut deps.storage, "Masonry", "9.9.9").unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg{ snapshot_limit: None }).unwrap_err();
+    assert!(matches!(err, ContractError::Migrate(MigrateError::Downgrade{ .. })));
+
+    // refuses to migrate another contract
+    cw2::set_contract_version(&mut deps.storage, "Treasury", "0.0.1").unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg{ snapshot_limit: None }).unwrap_err();
+    assert!(matches!(err, ContractError::Migrate(MigrateError::WrongContract{ .. })));
+}
+
+#[test]
+fn migrate_moves_a_long_history_in_batches(){
+    use crate::contract::migrate;
+    use crate::state::LEGACY_MASONRY_HISTORY;
+    use crate::util::{latest_snapshot_index, get_snapshot};
+    use IMasonry::msg::{MigrateMsg, MasonrySnapshot};
+
+    let mut deps = mock_dependencies(&[]);
+    cw2::set_contract_version(&mut deps.storage, "Masonry", "0.0.1").unwrap();
+    let history: Vec<MasonrySnapshot> = (0..5u128).map(|i| MasonrySnapshot{
+        time: Uint128::from(i),
+        reward_received: Uint128::from(i * 10),
+        reward_per_share: Uint128::from(i * 100)
+    }).collect();
+    LEGACY_MASONRY_HISTORY.save(&mut deps.storage, &history).unwrap();
+
+    let attribute = |res: &cosmwasm_std::Response, key: &str| res.attributes.iter()
+        .find(|attr| attr.key == key).map(|attr| attr.value.clone());
+    let msg = || MigrateMsg{ snapshot_limit: Some(2) };
+
+    let res = migrate(deps.as_mut(), mock_env(), msg()).unwrap();
+    assert_eq!(attribute(&res, "migrated_snapshots"), Some("2".to_string()));
+    assert_eq!(attribute(&res, "snapshots_left"), Some("3".to_string()));
+    // nothing runs on a half migrated history
+    let err = execute(deps.as_mut(), mock_env(), mock_info("alice", &[]), ExecuteMsg::ClaimReward{}).unwrap_err();
+    assert!(matches!(err, ContractError::HistoryMigrationInProgress{}));
+
+    // the same code is migrated again until the history is moved
+    migrate(deps.as_mut(), mock_env(), msg()).unwrap();
+    let res = migrate(deps.as_mut(), mock_env(), msg()).unwrap();
+    assert_eq!(attribute(&res, "migrated_snapshots"), Some("1".to_string()));
+    assert_eq!(attribute(&res, "snapshots_left"), Some("0".to_string()));
+    assert_eq!(latest_snapshot_index(&deps.storage).unwrap(), Uint128::from(4u128));
+    for (index, snapshot) in history.iter().enumerate() {
+        assert_eq!(&get_snapshot(&deps.storage, Uint128::from(index as u128)).unwrap(), snapshot);
+    }
+    assert_eq!(LEGACY_MASONRY_HISTORY.may_load(&deps.storage).unwrap(), None);
+
+    let res = migrate(deps.as_mut(), mock_env(), msg()).unwrap();
+    assert_eq!(attribute(&res, "migrated_snapshots"), None);
+    let res = execute(deps.as_mut(), mock_env(), mock_info("alice", &[]), ExecuteMsg::ClaimReward{});
+    assert!(!matches!(res, Err(ContractError::HistoryMigrationInProgress{})));
+}
@@ -5,16 +5,26 @@ use terraswap::querier::{query_token_balance};
 use cw20::{Cw20ExecuteMsg};
 
 use crate::error::ContractError;
+use cw_storage_plus::U128Key;
 use crate::state::{OPERATOR, TOMB, SHARE, TOTALSUPPLY, INITIALIZED, BALANCES, STATUS,
-    TREASURY, MASONS, MASONRY_HISTORY, WITHDRAW_LOCKUP_EPOCHS, REWARD_LOCKUP_EPOCHS};
+    TREASURY, MASONS, MASONRY_HISTORY, LATEST_SNAPSHOT_INDEX, WITHDRAW_LOCKUP_EPOCHS, REWARD_LOCKUP_EPOCHS};
     
 pub fn balance_of(storage: &dyn Storage, sender: Addr) -> Uint128{
-    BALANCES.load(storage, sender.clone()).unwrap()
+    BALANCES.may_load(storage, sender).unwrap().unwrap_or_default()
+}
+
+pub fn get_mason(storage: &dyn Storage, mason: Addr) -> StdResult<Masonseat> {
+    let seat = MASONS.may_load(storage, mason)?;
+    Ok(seat.unwrap_or(Masonseat {
+        last_snapshot_index: Uint128::zero(),
+        reward_earned: Uint128::zero(),
+        epoch_timer_start: Uint128::zero()
+    }))
 }
 
 pub fn check_onlyoperator(storage: &dyn Storage, sender: Addr) -> Result<Response, ContractError> {
-    let operator = OPERATOR.load(storage)?;
-    if operator != sender {
+    let operator = OPERATOR.may_load(storage)?;
+    if operator != Some(sender) {
         return Err(ContractError::Unauthorized{});
     }
     Ok(Response::new())
@@ -22,8 +32,8 @@ pub fn check_onlyoperator(storage: &dyn Storage, sender: Addr) -> Result<Respons
 pub fn check_onlyoneblock(storage: &mut dyn Storage, height: Uint128, sender: Addr)
     -> Result<Response, ContractError> 
 {
-    let status = STATUS.load(storage, (height.u128().into(), sender.clone()))?;
-    if status == true {
+    let status = STATUS.may_load(storage, (height.u128().into(), sender.clone()))?;
+    if status == Some(true) {
         return Err(ContractError::ContractGuard{ });
     }
 
@@ -40,7 +50,7 @@ pub fn check_mason_exists(storage: &dyn Storage, sender: Addr) -> Result<Respons
 
 pub fn update_reward(storage: &mut dyn Storage, mason: Addr) -> Result<Response, ContractError> {
     if mason != Addr::unchecked("".to_string()) {
-        let mut seat: Masonseat = MASONS.load(storage, mason.clone())?;
+        let mut seat: Masonseat = get_mason(storage, mason.clone())?;
         seat.reward_earned = earned(storage, mason.clone())?;
         seat.last_snapshot_index = latest_snapshot_index(storage)?;
         MASONS.save(storage, mason, &seat)?;
@@ -48,15 +58,26 @@ pub fn update_reward(storage: &mut dyn Storage, mason: Addr) -> Result<Response,
     Ok(Response::new())
 }
 pub fn latest_snapshot_index(storage: &dyn Storage) -> StdResult<Uint128>{
-    let masonry_history = MASONRY_HISTORY.load(storage)?;
-    Ok(Uint128::from((masonry_history.len()-1) as u128))
+    LATEST_SNAPSHOT_INDEX.load(storage)
+}
+pub fn get_snapshot(storage: &dyn Storage, index: Uint128) -> StdResult<MasonrySnapshot> {
+    MASONRY_HISTORY.load(storage, U128Key::from(index.u128()))
+}
+pub fn push_snapshot(storage: &mut dyn Storage, snapshot: &MasonrySnapshot) -> StdResult<Uint128> {
+    let index = match LATEST_SNAPSHOT_INDEX.may_load(storage)? {
+        Some(latest) => latest + Uint128::from(1u128),
+        None => Uint128::zero()
+    };
+    MASONRY_HISTORY.save(storage, U128Key::from(index.u128()), snapshot)?;
+    LATEST_SNAPSHOT_INDEX.save(storage, &index)?;
+    Ok(index)
 }
 pub fn earned(storage: &dyn Storage, mason: Addr) -> StdResult<Uint128>{
     let latest_rps = get_latest_snapshot(storage).reward_per_share;
     let stored_rps = get_last_snapshot_of(storage, mason.clone()).reward_per_share;
     let balance = balance_of(storage, mason.clone());
-    let mason = MASONS.load(storage, mason).unwrap();
-    let res = balance * (latest_rps-stored_rps) / Uint128::from((10u64).pow(18u32)) + mason.reward_earned;
+    let mason = get_mason(storage, mason)?;
+    let res = balance.multiply_ratio(latest_rps - stored_rps, (10u64).pow(18u32)) + mason.reward_earned;
     Ok(res)
 }
 pub fn check_not_initialized(storage: &dyn Storage) -> Result<Response, ContractError> {
@@ -67,16 +88,13 @@ pub fn check_not_initialized(storage: &dyn Storage) -> Result<Response, Contract
     Ok(Response::new())
 }
 pub fn get_latest_snapshot(storage: &dyn Storage) -> MasonrySnapshot {
-    let masonry_history = MASONRY_HISTORY.load(storage).unwrap();
-    let len = masonry_history.len();
-
-    masonry_history[len-1].clone()
+    let index = latest_snapshot_index(storage).unwrap();
+    get_snapshot(storage, index).unwrap()
 }
 
 pub fn get_last_snapshot_of(storage: &dyn Storage, mason: Addr) -> MasonrySnapshot {
-    let mason = MASONS.load(storage, mason).unwrap();
-    let masonry_history = MASONRY_HISTORY.load(storage).unwrap();
-    masonry_history[(mason.last_snapshot_index.u128() as usize)].clone()
+    let mason = get_mason(storage, mason).unwrap();
+    get_snapshot(storage, mason.last_snapshot_index).unwrap()
 }
 pub fn safe_transferfrom( storage: &dyn Storage, querier: &QuerierWrapper, token: Addr, _from: Addr, _to: Addr, _amount: Uint128) -> StdResult<CosmosMsg> {
     let token_balance = query_token_balance(querier, token.clone(), _from.clone()).unwrap();
@@ -105,6 +123,32 @@ pub fn safe_transferfrom( storage: &dyn Storage, querier: &QuerierWrapper, token
         msg: "transfer failed".to_string()
     })
 }
+pub fn safe_transfer(querier: &QuerierWrapper, token: Addr, _from: Addr, _to: Addr, _amount: Uint128) -> StdResult<CosmosMsg> {
+    let token_balance = query_token_balance(querier, token.clone(), _from).unwrap();
+
+    if token_balance > Uint128::zero() {
+        let mut amount = _amount;
+        if _amount > token_balance {
+            amount = token_balance;
+        }
+
+        let msg_transfer = WasmMsg::Execute {
+            contract_addr: token.to_string(),
+            msg: to_binary(
+                &Cw20ExecuteMsg::Transfer {
+                    recipient: _to.to_string(),
+                    amount
+                }
+            ).unwrap(),
+            funds: vec![]
+        };
+        return Ok(CosmosMsg::Wasm(msg_transfer));
+    }
+
+    Err(StdError::GenericErr{
+        msg: "transfer failed".to_string()
+    })
+}
 pub fn safe_share_transferfrom( storage: &dyn Storage, querier: &QuerierWrapper, _from: Addr, _to: Addr, _amount: Uint128) -> StdResult<CosmosMsg> {
     let share = SHARE.load(storage).unwrap();
 
@@ -115,4 +159,13 @@ pub fn safe_tomb_transferfrom(storage: &dyn Storage, querier: &QuerierWrapper, _
 
     safe_transferfrom(storage, querier, tomb, _from, _to, _amount)
 }
+pub fn safe_share_transfer(storage: &dyn Storage, querier: &QuerierWrapper, _from: Addr, _to: Addr, _amount: Uint128) -> StdResult<CosmosMsg> {
+    let share = SHARE.load(storage).unwrap();
+
+    safe_transfer(querier, share, _from, _to, _amount)
+}
+pub fn safe_tomb_transfer(storage: &dyn Storage, querier: &QuerierWrapper, _from: Addr, _to: Addr, _amount: Uint128) -> StdResult<CosmosMsg> {
+    let tomb = TOMB.load(storage).unwrap();
 
+    safe_transfer(querier, tomb, _from, _to, _amount)
+}
@@ -5,11 +5,12 @@ use cosmwasm_std::{
     Addr, DepsMut, Env, MessageInfo, Response, QuerierWrapper, Uint128, Storage, StdResult
 };
 use cw2::set_contract_version;
+use lib::migrate::{migrate_version, Version};
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg};
 use crate::state::{TOKEN0, TOKEN1, PAIR, PRICE0, PRICE1, START_TIME, EPOCH, PERIOD,
-    LAST_EPOCH_TIME, OPERATOR};
+    LAST_EPOCH_TIME, OPERATOR, OWNERSHIP};
 use terraswap::asset::{AssetInfo, Asset};
 use terraswap::pair::{QueryMsg as PairQueryMsg, SimulationResponse, PoolResponse};
 use terraswap::querier::{simulate, query_pair_info};
@@ -18,19 +19,22 @@ use terraswap::querier::{simulate, query_pair_info};
 const CONTRACT_NAME: &str = "Oracle";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+pub const ETHER: u128 = 1_000_000_000_000_000_000u128;
+
+// Price is the return of swapping one whole token (1e18 units), so that sub-unit prices keep their precision
 
 pub fn get_price(querier: &QuerierWrapper, _pair: Addr, asset_info: &AssetInfo) -> Uint128 {
     let offer_asset = Asset{
         info: asset_info.clone(),
-        amount: Uint128::from(1u128)
+        amount: Uint128::from(ETHER)
     };
     let sim_res: SimulationResponse = simulate( querier, _pair, &offer_asset ).unwrap();
 
     sim_res.return_amount
 }
 pub fn check_onlyoperator(storage: &dyn Storage, sender: Addr) -> Result<Response, ContractError> {
-    let operator = OPERATOR.load(storage)?;
-    if operator != sender {
+    let operator = OPERATOR.may_load(storage)?;
+    if operator != Some(sender) {
         return Err(ContractError::Unauthorized{});
     }
     Ok(Response::new())
@@ -57,7 +61,7 @@ pub fn check_epoch(storage: &dyn Storage, env: Env, sender: Addr)
 {
     let next_epoch_point = get_next_epoch_point(storage)?;
     if Uint128::from(env.block.time.seconds() as u128) < next_epoch_point {
-        if sender != OPERATOR.load(storage)? {
+        if OPERATOR.may_load(storage)? != Some(sender) {
             return Err(ContractError::Unauthorized{ });
         }
     } 
@@ -100,21 +104,50 @@ pub fn instantiate(
     let pair = msg.pair;
     PAIR.save(deps.storage, &pair)?;
 
+    load_pair(deps, pair)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    let previous = migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[])?;
+
+    let mut res = Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", previous.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION);
+
+    // 0.0.2: prices are stored per 1e18 units, the ones of 0.0.1 were for a single unit
+    // and Consult would read them 1e18 times too low until the next Update
+    if previous < Version::new(0, 0, 2) {
+        let pair = PAIR.load(deps.storage)?;
+        load_pair(deps, pair)?;
+        res = res.add_attribute("prices", "refreshed");
+    }
+    Ok(res)
+}
+
+// Stores the tokens of the pair and the current price of each
+pub fn load_pair(deps: DepsMut, pair: Addr) -> Result<(), ContractError> {
     let pair_info: PoolResponse = deps.querier.query_wasm_smart(
         pair.clone(),
         &PairQueryMsg::Pool{}
-    ).unwrap();
+    )?;
 
     let token0 = pair_info.assets[0].info.clone();
-    let token1 = pair_info.assets[0].info.clone();
+    let token1 = pair_info.assets[1].info.clone();
     TOKEN0.save( deps.storage, &token0)?;
     TOKEN1.save( deps.storage, &token1)?;
 
     PRICE0.save(deps.storage, &get_price(&deps.querier, pair.clone(), &token0))?;
     PRICE1.save(deps.storage, &get_price(&deps.querier, pair, &token1))?;
-
-    Ok(Response::new()
-        .add_attribute("method", "instantiate"))
+    Ok(())
 }
 
 #[entry_point]
@@ -128,6 +161,10 @@ pub fn execute(
         ExecuteMsg::Update {  } => try_update(deps, env, info),
         ExecuteMsg::SetPeriod{ period } => try_setperiod(deps, info, period),
         ExecuteMsg::SetEpoch{ epoch } => try_setepoch(deps, info, epoch),
+        ExecuteMsg::ProposeOperator{ operator } => Ok(OWNERSHIP.propose(deps.storage, &info.sender, operator)?),
+        ExecuteMsg::AcceptOperator{} => Ok(OWNERSHIP.accept(deps.storage, &info.sender)?),
+        ExecuteMsg::CancelProposal{} => Ok(OWNERSHIP.cancel(deps.storage, &info.sender)?),
+        ExecuteMsg::RenounceOperator{} => Ok(OWNERSHIP.renounce(deps.storage, &info.sender)?),
     }
 }
 
@@ -4,6 +4,8 @@ use cosmwasm_std::{
     QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
 };
 use cw20::{BalanceResponse, Cw20QueryMsg};
+use terraswap::asset::Asset;
+use terraswap::pair::{PoolResponse, QueryMsg as PairQueryMsg, SimulationResponse};
 use std::collections::HashMap;
 
 /// mock_dependencies is a drop-in replacement for cosmwasm_std::testing::mock_dependencies
@@ -26,6 +28,7 @@ pub fn mock_dependencies(
 pub struct WasmMockQuerier {
     base: MockQuerier,
     token_querier: TokenQuerier,
+    pair_querier: PairQuerier,
 }
 
 #[derive(Clone, Default)]
@@ -41,6 +44,30 @@ impl TokenQuerier {
     }
 }
 
+#[derive(Clone, Default)]
+pub struct PairQuerier {
+    pairs: HashMap<String, [Asset; 2]>,
+}
+
+impl PairQuerier {
+    pub fn new(pairs: &[(String, [Asset; 2])]) -> Self {
+        PairQuerier {
+            pairs: pairs.iter().cloned().collect(),
+        }
+    }
+
+    // constant product quote without fees
+    fn simulate(&self, pair: &str, offer_asset: &Asset) -> Option<Uint128> {
+        let assets = self.pairs.get(pair)?;
+        let (offer_pool, ask_pool) = if assets[0].info == offer_asset.info {
+            (assets[0].amount, assets[1].amount)
+        } else {
+            (assets[1].amount, assets[0].amount)
+        };
+        Some(ask_pool.multiply_ratio(offer_asset.amount, offer_pool + offer_asset.amount))
+    }
+}
+
 pub(crate) fn balances_to_map(
     balances: &[(String, &[(String, Uint128)])],
 ) -> HashMap<String, HashMap<String, Uint128>> {
@@ -76,6 +103,9 @@ impl WasmMockQuerier {
     pub fn handle_query(&self, request: &QueryRequest<Empty>) -> QuerierResult {
         match &request {
             QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => {
+                if let Ok(pair_msg) = from_binary::<PairQueryMsg>(msg) {
+                    return self.handle_pair_query(contract_addr, &pair_msg, msg.as_slice());
+                }
                 match from_binary(msg).unwrap() {
                     Cw20QueryMsg::Balance { address } => {
                         match self.token_querier.balances.get(contract_addr) {
@@ -105,11 +135,42 @@ impl WasmMockQuerier {
     }
 }
 
+impl WasmMockQuerier {
+    fn handle_pair_query(&self, contract_addr: &str, msg: &PairQueryMsg, request: &[u8]) -> QuerierResult {
+        let assets = match self.pair_querier.pairs.get(contract_addr) {
+            Some(assets) => assets,
+            None => return SystemResult::Err(SystemError::InvalidRequest {
+                error: "No pair found in pair map".to_string(),
+                request: request.into(),
+            }),
+        };
+        match msg {
+            PairQueryMsg::Pool {} => SystemResult::Ok(ContractResult::from(to_binary(&PoolResponse {
+                assets: assets.clone(),
+                total_share: Uint128::zero(),
+            }))),
+            PairQueryMsg::Simulation { offer_asset } => {
+                let return_amount = self.pair_querier.simulate(contract_addr, offer_asset).unwrap();
+                SystemResult::Ok(ContractResult::from(to_binary(&SimulationResponse {
+                    return_amount,
+                    spread_amount: Uint128::zero(),
+                    commission_amount: Uint128::zero(),
+                })))
+            }
+            _ => SystemResult::Err(SystemError::InvalidRequest {
+                error: "Not supported query".to_string(),
+                request: request.into(),
+            }),
+        }
+    }
+}
+
 impl WasmMockQuerier {
     pub fn new(base: MockQuerier<Empty>) -> Self {
         WasmMockQuerier {
             base,
             token_querier: TokenQuerier::default(),
+            pair_querier: PairQuerier::default(),
         }
     }
 
@@ -117,4 +178,9 @@ impl WasmMockQuerier {
     pub fn with_token_balances(&mut self, balances: &[(String, &[(String, Uint128)])]) {
         self.token_querier = TokenQuerier::new(balances);
     }
+
+    // configure the pair pool mock querier
+    pub fn with_pairs(&mut self, pairs: &[(String, [Asset; 2])]) {
+        self.pair_querier = PairQuerier::new(pairs);
+    }
 }
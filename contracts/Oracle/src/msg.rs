@@ -10,12 +10,19 @@ pub struct InstantiateMsg {
     pub start_time: Uint128
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
     Update {},
     SetPeriod{period: Uint128},
     SetEpoch{epoch: Uint128},
+    ProposeOperator{operator: Addr},
+    AcceptOperator{},
+    CancelProposal{},
+    RenounceOperator{},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -33,5 +40,8 @@ pub enum QueryMsg {
     GetPeriod{},
     GetStartTime{},
     GetLastEpochTime{},
-    NextEpochPoint{}
+    NextEpochPoint{},
+    PendingOperator{},
+    // the pair the prices are read from
+    Pair{}
 }
@@ -5,8 +5,8 @@ use cosmwasm_std::{
     to_binary, Binary, Deps, Env, StdResult, StdError
 };
 use crate::state::{TOKEN0, TOKEN1, PAIR, PRICE0, PRICE1, START_TIME, EPOCH, PERIOD,
-    LAST_EPOCH_TIME, OPERATOR};
-use crate::contract::{get_price, get_next_epoch_point};
+    LAST_EPOCH_TIME, OWNERSHIP};
+use crate::contract::{get_price, get_next_epoch_point, ETHER};
 use crate::msg::{QueryMsg};
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -20,7 +20,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 
             let amount_out;
             if token == token0 {
-                amount_out = price0 * amount_in;
+                amount_out = price0.multiply_ratio(amount_in, ETHER);
 
             } else {
                 if token != token1{
@@ -28,7 +28,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
                         msg: "Invalid Token".to_string()
                     });
                 }
-                amount_out = price1 * amount_in;
+                amount_out = price1.multiply_ratio(amount_in, ETHER);
             }
             to_binary(&amount_out)
         }
@@ -43,7 +43,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 
             let amount_out;
             if token == token0 {
-                amount_out = price0 * amount_in;
+                amount_out = price0.multiply_ratio(amount_in, ETHER);
 
             } else {
                 if token != token1{
@@ -51,7 +51,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
                         msg: "Invalid Token".to_string()
                     });
                 }
-                amount_out = price1 * amount_in;
+                amount_out = price1.multiply_ratio(amount_in, ETHER);
             }
             to_binary(&amount_out)
         }
@@ -75,5 +75,13 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::NextEpochPoint{} => {
             to_binary(&get_next_epoch_point(deps.storage)?)
         }
+
+        QueryMsg::PendingOperator{} => {
+            to_binary(&OWNERSHIP.pending_operator(deps.storage)?)
+        }
+
+        QueryMsg::Pair{} => {
+            to_binary(&PAIR.load(deps.storage)?)
+        }
     }
 }
@@ -1,9 +1,12 @@
 use cosmwasm_std::{Addr, Uint128};
 use cw_storage_plus::{Item};
 use terraswap::asset::{AssetInfo};
+use lib::operator::Ownership;
 
 //---epoch-----
 pub const OPERATOR: Item<Addr> = Item::new("operator");
+pub const PENDING_OPERATOR: Item<Addr> = Item::new("pending operator");
+pub const OWNERSHIP: Ownership = Ownership::new(&OPERATOR, &PENDING_OPERATOR);
 
 pub const PERIOD: Item<Uint128> = Item::new("period");
 pub const START_TIME: Item<Uint128> = Item::new("start_time");
@@ -1,10 +1,9 @@
 use super::*;
 use cosmwasm_std::{from_binary, Uint128, Addr};
-use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::testing::mock_env;
 
-use crate::contract::{execute, instantiate};
 use crate::query::{query};
-use crate::msg::{QueryMsg, ExecuteMsg, InstantiateMsg};
+use crate::msg::QueryMsg;
 
 use crate::mock_querier::mock_dependencies;
 
@@ -14,3 +13,48 @@ fn workflow(){
     
 }
 
+
+#[test]
+fn migrate_refreshes_the_prices(){
+    use crate::contract::{migrate, ETHER};
+    use crate::msg::MigrateMsg;
+    use crate::state::{PAIR, TOKEN0, TOKEN1, PRICE0, PRICE1};
+    use terraswap::asset::{Asset, AssetInfo};
+
+    let mut deps = mock_dependencies(&[]);
+    let tomb = AssetInfo::Token{ contract_addr: "tomb".to_string() };
+    let wftm = AssetInfo::Token{ contract_addr: "wftm".to_string() };
+    // 1.1 WFTM for a TOMB, the pool is deep enough for the swap to barely move it
+    deps.querier.with_pairs(&[("pair".to_string(), [
+        Asset{ info: tomb.clone(), amount: Uint128::from(1_000_000_000 * ETHER) },
+        Asset{ info: wftm.clone(), amount: Uint128::from(1_100_000_000 * ETHER) },
+    ])]);
+
+    // 0.0.1 stored the price of a single unit, TOKEN1 was never set apart from TOKEN0
+    cw2::set_contract_version(&mut deps.storage, "Oracle", "0.0.1").unwrap();
+    PAIR.save(&mut deps.storage, &Addr::unchecked("pair")).unwrap();
+    TOKEN0.save(&mut deps.storage, &tomb).unwrap();
+    TOKEN1.save(&mut deps.storage, &tomb).unwrap();
+    PRICE0.save(&mut deps.storage, &Uint128::from(1u128)).unwrap();
+    PRICE1.save(&mut deps.storage, &Uint128::zero()).unwrap();
+
+    let res = migrate(deps.as_mut(), mock_env(), MigrateMsg{}).unwrap();
+    assert!(res.attributes.iter().any(|attr| attr.key == "prices" && attr.value == "refreshed"));
+    assert_eq!(TOKEN1.load(&deps.storage).unwrap(), wftm);
+
+    // Consult reads the refreshed prices right away
+    let out: Uint128 = from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Consult{
+        token: tomb, amount_in: Uint128::from(2 * ETHER)
+    }).unwrap()).unwrap();
+    assert_eq!(out, Uint128::from(2_199_999_997_800_000_002u128));
+    let out: Uint128 = from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Consult{
+        token: wftm, amount_in: Uint128::from(ETHER)
+    }).unwrap()).unwrap();
+    assert_eq!(out, Uint128::from(909_090_908_264_462_810u128));
+
+    // migrating again under 0.0.2 leaves the prices to Update
+    PRICE0.save(&mut deps.storage, &Uint128::from(7u128)).unwrap();
+    let res = migrate(deps.as_mut(), mock_env(), MigrateMsg{}).unwrap();
+    assert!(!res.attributes.iter().any(|attr| attr.key == "prices"));
+    assert_eq!(PRICE0.load(&deps.storage).unwrap(), Uint128::from(7u128));
+}
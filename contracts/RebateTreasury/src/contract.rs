@@ -6,11 +6,12 @@ use cosmwasm_std::{
     Uint128, CosmosMsg, WasmMsg, Storage, StdResult, StdError, Deps
 };
 use cw2::set_contract_version;
+use lib::migrate::migrate_version;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg};
 use crate::state::{
-    OWNER, TOMB,  TOMB_ORACLE, TREASURY, ASSETS, VESTING, BOND_THRESHOLD,
+    OWNER, OWNERSHIP, TOMB,  TOMB_ORACLE, TREASURY, ASSETS, VESTING, BOND_THRESHOLD,
     BOND_FACTOR, SECONDARY_THRESHOLD, SECONDARY_FACTOR, BOND_VESTING,
     TOTAL_VESTED, LAST_BUY_BACK, BUYBACK_AMOUNT
 };
@@ -52,6 +53,19 @@ pub fn instantiate(
         .add_attribute("method", "instantiate"))
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    let previous = migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[])?;
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", previous.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -82,7 +96,19 @@ pub fn execute(
             => try_set_bond_parameter(deps, env, info, primary_threshold, primary_factor, second_threshold, second_factor, vesting_period),
 
         ExecuteMsg::RedeemAssetsForBuyback { tokens }
-            => try_redeem_assets_for_buyback(deps, env, info, tokens)
+            => try_redeem_assets_for_buyback(deps, env, info, tokens),
+
+        ExecuteMsg::ProposeOperator { operator }
+            => Ok(OWNERSHIP.propose(deps.storage, &info.sender, operator)?),
+
+        ExecuteMsg::AcceptOperator {  }
+            => Ok(OWNERSHIP.accept(deps.storage, &info.sender)?),
+
+        ExecuteMsg::CancelProposal {  }
+            => Ok(OWNERSHIP.cancel(deps.storage, &info.sender)?),
+
+        ExecuteMsg::RenounceOperator {  }
+            => Ok(OWNERSHIP.renounce(deps.storage, &info.sender)?),
     }
 }
     // Bond asset for discounted Tomb at bond rate
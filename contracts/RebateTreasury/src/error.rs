@@ -1,11 +1,19 @@
 use cosmwasm_std::StdError;
 use thiserror::Error;
+use lib::migrate::MigrateError;
+use lib::operator::OwnershipError;
 
 #[derive(Error, Debug)]
 pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Migrate(#[from] MigrateError),
+
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
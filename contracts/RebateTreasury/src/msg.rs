@@ -9,6 +9,9 @@ pub struct InstantiateMsg {
     pub treasury: Addr,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
@@ -34,7 +37,11 @@ pub enum ExecuteMsg {
     },
     RedeemAssetsForBuyback{
         tokens: Vec<Addr>
-    }
+    },
+    ProposeOperator{operator: Addr},
+    AcceptOperator{},
+    CancelProposal{},
+    RenounceOperator{},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -43,7 +50,8 @@ pub enum QueryMsg {
     GetTombReturn{token: Addr, amount: Uint128},
     GetBondPremium{},
     GetTombPrice{},
-    GetTokenPrice{token: Addr}
+    GetTokenPrice{token: Addr},
+    PendingOperator{}
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
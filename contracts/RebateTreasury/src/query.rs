@@ -6,6 +6,7 @@ use cosmwasm_std::{
 };
 
 use crate::msg::{QueryMsg,};
+use crate::state::OWNERSHIP;
 
 use crate::util::{get_tomb_price, get_tomb_return, get_bond_premium,
     get_token_price
@@ -28,6 +29,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 
         QueryMsg::GetTokenPrice { token } => {
             to_binary(&get_token_price(deps.storage, &deps.querier, token)?)
+        },
+
+        QueryMsg::PendingOperator {  } => {
+            to_binary(&OWNERSHIP.pending_operator(deps.storage)?)
         }
     }
 }
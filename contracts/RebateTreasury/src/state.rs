@@ -1,9 +1,12 @@
 use cosmwasm_std::{Addr, Uint128};
 use cw_storage_plus::{Item, Map, U128Key};
+use lib::operator::Ownership;
 
 use crate::msg::{Asset, VestingSchedule};
 
 pub const OWNER: Item<Addr> = Item::new("owner");
+pub const PENDING_OWNER: Item<Addr> = Item::new("pending owner");
+pub const OWNERSHIP: Ownership = Ownership::new(&OWNER, &PENDING_OWNER);
 
 pub const TOMB: Item<Addr> = Item::new("tomb");
 pub const TOMB_ORACLE: Item<Addr> = Item::new("tomb oracle");
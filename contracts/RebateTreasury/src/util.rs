@@ -20,8 +20,8 @@ use terraswap::pair::{QueryMsg as PairQueryMsg, SimulationResponse, PoolResponse
 pub const ETHER: u128 = 1_000_000_000_000_000_000u128;
 
 pub fn check_onlyowner(storage: &dyn Storage, sender: Addr) -> Result<Response, ContractError> {
-    let owner = OWNER.load(storage)?;
-    if owner != sender {
+    let owner = OWNER.may_load(storage)?;
+    if owner != Some(sender) {
         return Err(ContractError::Unauthorized{});
     }
     Ok(Response::new())
@@ -6,17 +6,25 @@ use cosmwasm_std::{
 };
 
 use crate::msg::{QueryMsg};
-use crate::state::{OPERATOR, POOLINFO, USERINFO, TOTALALLOCPOINT};
+use crate::state::{OPERATOR, OWNERSHIP, PAUSE, POOLINFO, USERINFO, TOTALALLOCPOINT};
 use crate::contract::{get_generated_reward, balance_of, ETHER};
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::GetOwner{ } => {
-            let owner = OPERATOR.load(deps.storage).unwrap();
+            let owner = OPERATOR.load(deps.storage)?;
             to_binary(&owner)
         }
 
+        QueryMsg::PendingOperator{ } => {
+            to_binary(&OWNERSHIP.pending_operator(deps.storage)?)
+        }
+
+        QueryMsg::PauseStatus{ } => {
+            to_binary(&PAUSE.status(deps.storage)?)
+        }
+
         QueryMsg::GetGeneratedReward{ from_time, to_time } => {
             let res: u128 = get_generated_reward(deps.storage, from_time, to_time);
             to_binary(&res)
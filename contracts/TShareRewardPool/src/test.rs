@@ -4,7 +4,9 @@ use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 
 use crate::contract::{execute, instantiate};
 use crate::query::{query};
-use crate::msg::{QueryMsg, ExecuteMsg, InstantiateMsg, PoolInfo, UserInfo};
+use crate::msg::{QueryMsg, ExecuteMsg, InstantiateMsg, PoolInfo, UserInfo, Operation};
+use crate::error::ContractError;
+use lib::pause::{PauseError, PauseStatus};
 
 use crate::mock_querier::mock_dependencies;
 
@@ -74,14 +76,20 @@ fn workflow(){
     let info = mock_info("user1", &[]);
     let res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
     println!("Emergency Withdraw {:?}", res);
-//set operator
-    let msg = ExecuteMsg::SetOperator{
+//propose operator
+    let msg = ExecuteMsg::ProposeOperator{
         operator: Addr::unchecked("user1".to_string()),
     };
 
     let info = mock_info("admin", &[]);
     let res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
-    println!("Set Operator {:?}", res);
+    println!("Propose Operator {:?}", res);
+//accept operator
+    let msg = ExecuteMsg::AcceptOperator{ };
+
+    let info = mock_info("user1", &[]);
+    let res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    println!("Accept Operator {:?}", res);
 //Governance Recover Unsupported
     let msg = ExecuteMsg::GovernanceRecoverUnsupported{
         token: Addr::unchecked("token3".to_string()),
@@ -111,3 +119,49 @@ fn workflow(){
 
 }
 
+#[test]
+fn guardian_pauses_deposits_and_withdrawals(){
+    let mut deps = mock_dependencies(&[]);
+    let msg = InstantiateMsg{
+        TSHARE: "tomb".to_string(),
+        POOLSTARTTIME: Uint128::from(mock_env().block.time.seconds() + 1000)
+    };
+    let admin = mock_info("admin", &[]);
+    instantiate(deps.as_mut(), mock_env(), admin.clone(), msg).unwrap();
+    let msg = ExecuteMsg::Add{
+        alloc_point: Uint128::from(1u128),
+        token: Addr::unchecked("token1"),
+        with_update: false,
+        last_reward_time: Uint128::zero()
+    };
+    execute(deps.as_mut(), mock_env(), admin.clone(), msg).unwrap();
+
+    let user = mock_info("user1", &[]);
+    let deposit = ExecuteMsg::Deposit{ pid: Uint128::zero(), amount: Uint128::from(10_000u64) };
+    execute(deps.as_mut(), mock_env(), user.clone(), deposit.clone()).unwrap();
+
+    let guardian = mock_info("guardian", &[]);
+    let pause = ExecuteMsg::Pause{ operations: vec![Operation::Deposit, Operation::Withdraw] };
+    let err = execute(deps.as_mut(), mock_env(), guardian.clone(), pause.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::Pause(PauseError::Unauthorized{ })));
+    let msg = ExecuteMsg::SetGuardian{ guardian: Some(guardian.sender.clone()) };
+    let err = execute(deps.as_mut(), mock_env(), user.clone(), msg.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized{ }));
+    execute(deps.as_mut(), mock_env(), admin.clone(), msg).unwrap();
+    execute(deps.as_mut(), mock_env(), guardian.clone(), pause).unwrap();
+
+    let status: PauseStatus<Operation> = from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::PauseStatus{ }).unwrap()).unwrap();
+    assert_eq!(status.paused, vec![Operation::Deposit, Operation::Withdraw]);
+    let err = execute(deps.as_mut(), mock_env(), user.clone(), deposit.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::Pause(PauseError::Paused{ })));
+    let withdraw = ExecuteMsg::Withdraw{ pid: Uint128::zero(), amount: Uint128::from(1_000u64) };
+    let err = execute(deps.as_mut(), mock_env(), user.clone(), withdraw).unwrap_err();
+    assert!(matches!(err, ContractError::Pause(PauseError::Paused{ })));
+
+    // users can always get their tokens back
+    execute(deps.as_mut(), mock_env(), user.clone(), ExecuteMsg::EmergencyWithdraw{ pid: Uint128::zero() }).unwrap();
+
+    let unpause = ExecuteMsg::Unpause{ operations: vec![Operation::Deposit] };
+    execute(deps.as_mut(), mock_env(), guardian, unpause).unwrap();
+    execute(deps.as_mut(), mock_env(), user, deposit).unwrap();
+}
@@ -9,6 +9,9 @@ pub struct InstantiateMsg {
     pub POOLSTARTTIME: Uint128
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
@@ -37,9 +40,23 @@ pub enum ExecuteMsg {
     EmergencyWithdraw{
         pid: Uint128
     },
-    SetOperator{
+    ProposeOperator{
         operator: Addr
     },
+    AcceptOperator{ },
+    CancelProposal{ },
+    RenounceOperator{ },
+    // None leaves nobody able to pause
+    SetGuardian{
+        guardian: Option<Addr>
+    },
+    // guardian only, EmergencyWithdraw stays available
+    Pause{
+        operations: Vec<Operation>
+    },
+    Unpause{
+        operations: Vec<Operation>
+    },
     GovernanceRecoverUnsupported{
         token: Addr,
         amount: Uint128,
@@ -51,6 +68,8 @@ pub enum ExecuteMsg {
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     GetOwner{ },
+    PendingOperator{ },
+    PauseStatus{ },
     GetGeneratedReward{
         from_time: Uint128,
         to_time: Uint128
@@ -66,6 +85,14 @@ pub enum QueryMsg {
     }
 }
 
+// What the guardian can pause, withdrawing also pays the pending rewards
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    Deposit,
+    Withdraw
+}
+
 // Info of each user.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct UserInfo {
@@ -75,14 +75,20 @@ fn workflow(){
     let info = mock_info("user1", &[]);
     let res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
     println!("Emergency Withdraw {:?}", res);
-//set operator
-    let msg = ExecuteMsg::SetOperator{
+//propose operator
+    let msg = ExecuteMsg::ProposeOperator{
         operator: Addr::unchecked("user1".to_string()),
     };
 
     let info = mock_info("admin", &[]);
     let res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
-    println!("Set Operator {:?}", res);
+    println!("Propose Operator {:?}", res);
+//accept operator
+    let msg = ExecuteMsg::AcceptOperator{ };
+
+    let info = mock_info("user1", &[]);
+    let res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    println!("Accept Operator {:?}", res);
 //Governance Recover Unsupported
     let msg = ExecuteMsg::GovernanceRecoverUnsupported{
         token: Addr::unchecked("token3".to_string()),
@@ -6,15 +6,18 @@ use cosmwasm_std::{
     Uint128, CosmosMsg, WasmMsg, Storage
 };
 use cw2::set_contract_version;
+use lib::migrate::migrate_version;
 use cw20::{Cw20ExecuteMsg, Cw20QueryMsg, BalanceResponse as Cw20BalanceResponse};
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, UserInfo, PoolInfo};
-use crate::state::{OPERATOR, TOMB, POOLINFO, USERINFO, TOTALALLOCPOINT, 
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, UserInfo, PoolInfo, Operation};
+use crate::state::{OPERATOR, OWNERSHIP, PAUSE, TOMB, POOLINFO, USERINFO, TOTALALLOCPOINT, 
     POOLSTARTTIME, EPOCHENDTIMES, EPOCHTOMBPERSECOND};
 
 // version info for migration info
-const CONTRACT_NAME: &str = "TombGenesisRewardPool";
+const CONTRACT_NAME: &str = "TombRewardPool";
+// name stored by deployments made before the copy-paste of the genesis pool name was fixed
+const LEGACY_CONTRACT_NAME: &str = "TombGenesisRewardPool";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 const EPOCHTOTALREWARDS:[u128;2] = [80_000_000_000_000_000_000_000u128, 
@@ -60,6 +63,19 @@ pub fn instantiate(
         .add_attribute("method", "instantiate"))
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    let previous = migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[LEGACY_CONTRACT_NAME])?;
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", previous.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
 pub fn balance_of(querier: QuerierWrapper, _token: &Addr, _address: &Addr) -> u128 {
     let token_balance: Cw20BalanceResponse = querier.query_wasm_smart(
         _token,
@@ -233,8 +249,26 @@ pub fn execute(
         ExecuteMsg::EmergencyWithdraw{ pid }
             => try_emergency_withdraw(deps, info, pid),
 
-        ExecuteMsg::SetOperator{ operator }
-            => try_setoperator(deps, info, operator),
+        ExecuteMsg::ProposeOperator{ operator }
+            => Ok(OWNERSHIP.propose(deps.storage, &info.sender, operator)?),
+
+        ExecuteMsg::AcceptOperator{ }
+            => Ok(OWNERSHIP.accept(deps.storage, &info.sender)?),
+
+        ExecuteMsg::CancelProposal{ }
+            => Ok(OWNERSHIP.cancel(deps.storage, &info.sender)?),
+
+        ExecuteMsg::RenounceOperator{ }
+            => Ok(OWNERSHIP.renounce(deps.storage, &info.sender)?),
+
+        ExecuteMsg::SetGuardian{ guardian }
+            => try_set_guardian(deps, info, guardian),
+
+        ExecuteMsg::Pause{ operations }
+            => Ok(PAUSE.pause(deps.storage, &info.sender, operations)?),
+
+        ExecuteMsg::Unpause{ operations }
+            => Ok(PAUSE.unpause(deps.storage, &info.sender, operations)?),
 
         ExecuteMsg::GovernanceRecoverUnsupported{ token, amount, to }
             => try_governance_recover_unsupported(deps, env, info, token, amount, to),
@@ -289,20 +323,18 @@ pub fn try_governance_recover_unsupported(
         .add_message(CosmosMsg::Wasm(msg_transfer))
         .add_attribute("action", "Governance recover unsupported"))
 }
-pub fn try_setoperator(
+pub fn try_set_guardian(
     deps: DepsMut,
     info: MessageInfo,
-    operator: Addr
+    guardian: Option<Addr>
 )
     -> Result<Response, ContractError>
 {
-    let _operator = OPERATOR.load(deps.storage)?;
-    if _operator != info.sender {
+    let operator = OPERATOR.load(deps.storage)?;
+    if operator != info.sender {
         return Err(ContractError::Unauthorized{ });
     }
-    OPERATOR.save(deps.storage, &operator)?;
-    Ok(Response::new()
-        .add_attribute("action", "Set Operator"))
+    Ok(PAUSE.set_guardian(deps.storage, guardian)?)
 }
 pub fn try_emergency_withdraw(
     deps: DepsMut,
@@ -349,6 +381,7 @@ pub fn try_withdraw(
 )
     -> Result<Response, ContractError>
 {
+    PAUSE.assert_not_paused(deps.storage, &Operation::Withdraw)?;
     let _sender = info.sender;
     let pool_info = &mut POOLINFO.load(deps.storage)?;
     let pool = &mut pool_info[pid.u128() as usize];
@@ -403,6 +436,7 @@ pub fn try_deposit(
 )
     -> Result<Response, ContractError>
 {
+    PAUSE.assert_not_paused(deps.storage, &Operation::Deposit)?;
     let _sender = info.sender;
     let pool_info = &mut POOLINFO.load(deps.storage)?;
     let pool = &mut pool_info[pid.u128() as usize];
@@ -8,6 +8,9 @@ pub struct InstantiateMsg {
     pub POOLSTARTTIME: Uint128
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
@@ -36,9 +39,23 @@ pub enum ExecuteMsg {
     EmergencyWithdraw{
         pid: Uint128
     },
-    SetOperator{
+    ProposeOperator{
         operator: Addr
     },
+    AcceptOperator{ },
+    CancelProposal{ },
+    RenounceOperator{ },
+    // None leaves nobody able to pause
+    SetGuardian{
+        guardian: Option<Addr>
+    },
+    // guardian only, EmergencyWithdraw stays available
+    Pause{
+        operations: Vec<Operation>
+    },
+    Unpause{
+        operations: Vec<Operation>
+    },
     GovernanceRecoverUnsupported{
         token: Addr,
         amount: Uint128,
@@ -50,6 +67,8 @@ pub enum ExecuteMsg {
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     GetOwner{ },
+    PendingOperator{ },
+    PauseStatus{ },
     GetGeneratedReward{
         from_time: Uint128,
         to_time: Uint128
@@ -65,6 +84,14 @@ pub enum QueryMsg {
     }
 }
 
+// What the guardian can pause, withdrawing also pays the pending rewards
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    Deposit,
+    Withdraw
+}
+
 // Info of each user.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct UserInfo {
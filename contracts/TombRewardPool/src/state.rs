@@ -1,8 +1,15 @@
 use cosmwasm_std::{Addr, Uint128};
 use cw_storage_plus::{Item, Map, U128Key};
-use crate::msg::{UserInfo, PoolInfo};
+use lib::operator::Ownership;
+use lib::pause::Pause;
+use crate::msg::{UserInfo, PoolInfo, Operation};
 
 pub const OPERATOR: Item<Addr> = Item::new("OPERATOR");
+pub const PENDING_OPERATOR: Item<Addr> = Item::new("PENDING_OPERATOR");
+pub const OWNERSHIP: Ownership = Ownership::new(&OPERATOR, &PENDING_OPERATOR);
+pub const GUARDIAN: Item<Addr> = Item::new("GUARDIAN");
+pub const PAUSED: Item<Vec<Operation>> = Item::new("PAUSED");
+pub const PAUSE: Pause<Operation> = Pause::new(&GUARDIAN, &PAUSED);
 pub const TOMB: Item<Addr> = Item::new("TOMB");
 
 // Info of each pool.
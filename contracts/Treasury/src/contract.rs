@@ -2,15 +2,20 @@
 use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
-    Addr, to_binary, DepsMut, Env, MessageInfo, Response, QuerierWrapper,
-    Uint128, CosmosMsg, WasmMsg, Storage, StdResult, StdError
+    Addr, to_binary, DepsMut, Env, MessageInfo, Response,
+    Uint128, CosmosMsg, WasmMsg, Storage, StdResult, SubMsg, Reply, Event
 };
+use cosmwasm_std::from_binary;
 use cw2::set_contract_version;
+use cw20::Cw20ReceiveMsg;
+use lib::migrate::migrate_version;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg};
+use crate::events::{parameter_change, json_value, optional_value, seigniorage, timelock_transaction, token_recovery};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, SupplyTier, KeeperBounty, CatchUpPolicy, QueuedTransaction, Operation, CircuitBreaker,
+    SeigniorageRecipient, Cw20HookMsg, BondPricing, BondQuote, BondQuoteFailure};
 use crate::state::{
-    OPERATOR, INITIALIZED, START_TIME, EPOCH, EPOCH_SUPPLY_CONTRACTION_LEFT,
+    OPERATOR, OWNERSHIP, INITIALIZED, START_TIME, EPOCH, PERIOD, PERIOD_ANCHOR, EPOCH_SUPPLY_CONTRACTION_LEFT,
     EXCLUDED_FROM_TOTALSUPPLY, TOMB, TBOND, TSHARE, MASONRY,
     BOND_TREASURY, TOMB_ORACLE, TOMB_PRICE_ONE, TOMB_PRICE_CEILING,
     SEIGNIORAGE_SAVED, SUPPLY_TIERS, MAX_EXPANSION_TIERS,
@@ -21,23 +26,57 @@ use crate::state::{
     PREVIOUS_EPOCH_TOMB_PRICE, MAX_DISCOUNT_RATE,
     MAX_PREMIUM_RATE, DISCOUNT_PERCENT, PREMIUM_PERCENT,
     PREMIUM_THRESHOLD, MINTING_FACTOR_FOR_PAYING_DEBT,
-    DAOFUND, DAOFUND_SHARED_PERCENT, DEVFUND,
-    DEVFUND_SHARED_PERCENT
+    DAOFUND, DAOFUND_SHARED_PERCENT, DEVFUND, DEVFUND_SHARED_PERCENT,
+    SEIGNIORAGE_RECIPIENTS, SEIGNIORAGE_PAID, EPOCH_HISTORY, KEEPER_BOUNTY, CATCH_UP_POLICY,
+    TIMELOCK_DELAY, NEXT_TRANSACTION_ID, QUEUED_TRANSACTIONS, PAUSE, CIRCUIT_BREAKER, CIRCUIT_BREAKER_TRIPS
 };
-use crate::util::{ETHER, check_onlyoperator, check_operator, check_condition, get_tomb_price, 
-    get_bond_discount_rate, get_tomb_circulating_supply, get_total_supply,
-    get_bond_premium_rate, check_epoch
+use crate::util::{ETHER, check_onlyoperator, check_onlyself, check_operator, check_condition, get_tomb_price, 
+    quote_buy_bonds, quote_redeem_bonds, check_epoch, get_seigniorage_shares, get_bond_treasury_mint_amount,
+    get_max_supply_expansion_percent, get_epoch_allocation, check_period, next_epoch_point, is_timelocked,
+    get_bond_pricing, check_not_initialized
 };
+use crate::state::{GUARDIAN, BOND_PRICING};
+use lib::pause::PauseError;
+use cw_storage_plus::U64Key;
 use terraswap::querier::{query_token_balance};
 use Oracle::msg::{ExecuteMsg as OracleMsg};
 use BasisAsset::msg::{ExecuteMsg as BasisAssetMsg};
+use BondTreasury::msg::{ExecuteMsg as BondTreasuryMsg};
 use IMasonry::msg::{ExecuteMsg as MasonryMsg};
-use BondTreasury::msg::{QueryMsg as BondTreasuryQuery};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "Treasury";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
-pub const PERIOD: u128 = 21_600u128;
+// epoch length of the deployments that predate a configurable period
+pub const DEFAULT_PERIOD: u128 = 21_600u128;
+// every excluded address costs a balance query when computing the circulating supply
+pub const MAX_EXCLUDED_FROM_TOTALSUPPLY: usize = 20;
+pub const UPDATE_TOMB_PRICE_REPLY_ID: u64 = 1;
+// the allocation walks the tiers from the top
+pub const MAX_SUPPLY_TIERS: usize = 20;
+// bounds of a tier expansion cap, in basis points
+const MIN_EXPANSION_TIER: Uint128 = Uint128::new(10);
+const MAX_EXPANSION_TIER: Uint128 = Uint128::new(1_000);
+// keeper bounty bounds: 1_000 TOMB per epoch, 5% of the expansion
+pub const MAX_KEEPER_BOUNTY: Uint128 = Uint128::new(1_000 * ETHER);
+pub const MAX_KEEPER_BOUNTY_PERCENT: Uint128 = Uint128::new(500);
+// every recipient costs a transfer in each allocation
+pub const MAX_SEIGNIORAGE_RECIPIENTS: usize = 10;
+// the former 30% DAO and 10% dev fund caps together
+pub const MAX_SEIGNIORAGE_RECIPIENTS_SHARE: Uint128 = Uint128::new(4_000);
+// breakpoints of each piecewise linear bond curve
+pub const MAX_PRICE_POINTS: usize = 10;
+// circuit breaker trigger bounds: a 1% to 100% TWAP move between two epochs
+const MIN_BREAKER_PRICE_MOVE: Uint128 = Uint128::new(100);
+const MAX_BREAKER_PRICE_MOVE: Uint128 = Uint128::new(10_000);
+// re-anchor bounds: 1 to 28 tripped allocations in a row
+const MIN_BREAKER_REANCHOR_AFTER: Uint128 = Uint128::new(1);
+const MAX_BREAKER_REANCHOR_AFTER: Uint128 = Uint128::new(28);
+// timelock delay bounds: 1 hour to 30 days
+const MIN_TIMELOCK_DELAY: Uint128 = Uint128::new(3_600);
+const MAX_TIMELOCK_DELAY: Uint128 = Uint128::new(2_592_000);
+// queued setters not executed within 14 days of their eta expire
+pub const TIMELOCK_GRACE_PERIOD: Uint128 = Uint128::new(1_209_600);
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -52,19 +91,79 @@ pub fn instantiate(
         .add_attribute("method", "instantiate"))
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    let previous = migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[])?;
+    // epochs used to be START_TIME + EPOCH * DEFAULT_PERIOD
+    if PERIOD.may_load(deps.storage)?.is_none() {
+        if let Some(start_time) = START_TIME.may_load(deps.storage)? {
+            PERIOD.save(deps.storage, &Uint128::from(DEFAULT_PERIOD))?;
+            PERIOD_ANCHOR.save(deps.storage, &(Uint128::zero(), start_time))?;
+        }
+    }
+    // the DAO and dev funds used to be the only recipients
+    if SEIGNIORAGE_RECIPIENTS.may_load(deps.storage)?.is_none() {
+        let mut recipients = vec![];
+        for (name, fund, share) in [("dao_fund", DAOFUND, DAOFUND_SHARED_PERCENT), ("dev_fund", DEVFUND, DEVFUND_SHARED_PERCENT)] {
+            if let (Some(address), Some(share)) = (fund.may_load(deps.storage)?, share.may_load(deps.storage)?) {
+                if !share.is_zero() {
+                    recipients.push(SeigniorageRecipient { name: name.to_string(), address, share });
+                }
+            }
+            fund.remove(deps.storage);
+            share.remove(deps.storage);
+        }
+        SEIGNIORAGE_RECIPIENTS.save(deps.storage, &recipients)?;
+    }
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", previous.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    if is_timelocked(&msg) && TIMELOCK_DELAY.may_load(deps.storage)?.is_some() {
+        return Err(ContractError::TreasuryError {
+            msg: "Treasury: setters must be queued while the timelock is enabled".to_string()
+        });
+    }
+    dispatch(deps, env, info, msg)
+}
+
+fn dispatch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Initialize{ tomb, tbond, tshare, tomb_oracle, masonry, genesis_pool, bond_treasury, start_time }
-            => try_initialize(deps, env, info, tomb, tbond, tshare, tomb_oracle, masonry, genesis_pool, bond_treasury, start_time  ),
+        ExecuteMsg::Initialize{ tomb, tbond, tshare, tomb_oracle, masonry, genesis_pool, bond_treasury, start_time, period }
+            => try_initialize(deps, env, info, tomb, tbond, tshare, tomb_oracle, masonry, genesis_pool, bond_treasury, start_time, period  ),
+
+        ExecuteMsg::ProposeOperator { operator }
+            =>  Ok(OWNERSHIP.propose(deps.storage, &info.sender, operator)?),
+
+        ExecuteMsg::AcceptOperator {  }
+            =>  Ok(OWNERSHIP.accept(deps.storage, &info.sender)?),
+
+        ExecuteMsg::CancelProposal {  }
+            =>  Ok(OWNERSHIP.cancel(deps.storage, &info.sender)?),
 
-        ExecuteMsg::SetOperator { operator } 
-            =>  try_setoperator(deps, env, info, operator),
+        ExecuteMsg::RenounceOperator {  }
+            =>  Ok(OWNERSHIP.renounce(deps.storage, &info.sender)?),
+
+        ExecuteMsg::SetPeriod { period }
+            =>  try_set_period(deps, info, period),
 
         ExecuteMsg::SetMasonry { masonry } 
             =>  try_setmasonry(deps, env, info, masonry),
@@ -84,6 +183,12 @@ pub fn execute(
         ExecuteMsg::SetSupplyTiersEntry{ index, value }
             =>  try_set_supply_tiers_entry(deps, env, info, index, value),
 
+        ExecuteMsg::SetMaxExpansionTiersEntry{ index, value }
+            =>  try_set_max_expansion_tiers_entry(deps, env, info, index, value),
+
+        ExecuteMsg::SetSupplyTiers{ tiers }
+            =>  try_set_supply_tiers(deps, env, info, tiers),
+
         ExecuteMsg::SetBondDepletionFloorPercent{ bond_depletion_floor_percent }
             =>  try_set_bond_depletion_floor_percent(deps, env, info, bond_depletion_floor_percent),
 
@@ -96,8 +201,8 @@ pub fn execute(
         ExecuteMsg::SetBootstrap{ bootstrap_epochs, bootstrap_supply_expansion_percent }
             =>  try_set_bootstrap(deps, env, info, bootstrap_epochs, bootstrap_supply_expansion_percent),
         
-        ExecuteMsg::SetExtraFunds { dao_fund, dao_fund_shared_percent, dev_fund, dev_fund_shared_percent }
-            =>  try_set_extra_funds(deps, env, info, dao_fund, dao_fund_shared_percent, dev_fund, dev_fund_shared_percent),
+        ExecuteMsg::SetSeigniorageRecipients { recipients }
+            =>  try_set_seigniorage_recipients(deps, info, recipients),
         
         ExecuteMsg::SetMaxDiscountRate { max_discount_rate }
             =>  try_set_max_discount_rate(deps, env, info, max_discount_rate),
@@ -120,6 +225,45 @@ pub fn execute(
         ExecuteMsg::SetBondSupplyExpansionPercent { bond_supply_expansion_percent }
             =>  try_set_bond_supply_expansion_percent(deps, env, info, bond_supply_expansion_percent),
 
+        ExecuteMsg::AddExcludedAddress { address }
+            =>  try_add_excluded_address(deps, info, address),
+
+        ExecuteMsg::RemoveExcludedAddress { address }
+            =>  try_remove_excluded_address(deps, info, address),
+
+        ExecuteMsg::SetKeeperBounty { bounty }
+            =>  try_set_keeper_bounty(deps, info, bounty),
+
+        ExecuteMsg::SetCatchUpPolicy { policy }
+            =>  try_set_catch_up_policy(deps, info, policy),
+
+        ExecuteMsg::SetBondPricing { pricing }
+            =>  try_set_bond_pricing(deps, info, pricing),
+
+        ExecuteMsg::SetCircuitBreaker { breaker }
+            =>  try_set_circuit_breaker(deps, info, breaker),
+
+        ExecuteMsg::SetTimelockDelay { delay }
+            =>  try_set_timelock_delay(deps, info, delay),
+
+        ExecuteMsg::QueueTransaction { msg }
+            =>  try_queue_transaction(deps, env, info, *msg),
+
+        ExecuteMsg::ExecuteTransaction { id }
+            =>  try_execute_transaction(deps, env, info, id),
+
+        ExecuteMsg::CancelTransaction { id }
+            =>  try_cancel_transaction(deps, info, id),
+
+        ExecuteMsg::SetGuardian { guardian }
+            =>  try_set_guardian(deps, info, guardian),
+
+        ExecuteMsg::Pause { operations }
+            =>  Ok(PAUSE.pause(deps.storage, &info.sender, operations)?),
+
+        ExecuteMsg::Unpause { operations }
+            =>  Ok(PAUSE.unpause(deps.storage, &info.sender, operations)?),
+
         ExecuteMsg::UpdateTombPrice{ }
             =>  try_update_tomb_price(deps, env, info),
 
@@ -128,6 +272,9 @@ pub fn execute(
 
         ExecuteMsg::RedeemBonds { bond_amount, target_price }
             => try_redeem_bonds(deps, env, info, bond_amount, target_price),
+
+        ExecuteMsg::Receive(msg)
+            => try_receive(deps, env, info, msg),
             
         ExecuteMsg::SendToMasonry { amount }
             => try_send_to_masonry(deps, env, info, amount),
@@ -141,12 +288,18 @@ pub fn execute(
         ExecuteMsg::GovernanceRecoverUnsupported { token, amount, to }
             => try_governance_recover_unsupported(deps, env, info, token, amount, to),
 
-        ExecuteMsg::MasonrySetOperator { operator }
-            => try_masonry_set_operator(deps, info, operator),
+        ExecuteMsg::MasonryProposeOperator { operator }
+            => try_masonry_propose_operator(deps, info, operator),
+
+        ExecuteMsg::MasonryAcceptOperator {  }
+            => try_masonry_accept_operator(deps, info),
 
         ExecuteMsg::MasonrySetLockup { withdraw_lockup_epochs, reward_lockup_epochs }
             => try_masonry_set_lockup(deps, info, withdraw_lockup_epochs, reward_lockup_epochs),
 
+        ExecuteMsg::MasonrySetGuardian { guardian }
+            => try_masonry_set_guardian(deps, info, guardian),
+
         ExecuteMsg::MasonryAllocationSeigniorage { amount }
             => try_masonry_allocation_seigniorage(deps, info, amount),
         
@@ -165,10 +318,14 @@ pub fn try_initialize(
     masonry: Addr,
     genesis_pool: Addr,
     bond_treasury: Addr,
-    start_time: Uint128
+    start_time: Uint128,
+    period: Uint128
 )
     -> Result<Response, ContractError>
 {
+    // a second Initialize would reset the operator and every parameter at once
+    check_not_initialized(deps.storage)?;
+    check_period(period)?;
     TOMB.save(deps.storage, &tomb)?;
     TBOND.save(deps.storage, &tbond)?;
     TSHARE.save(deps.storage, &tshare)?;
@@ -176,6 +333,10 @@ pub fn try_initialize(
     MASONRY.save(deps.storage, &masonry)?;
     BOND_TREASURY.save(deps.storage, &bond_treasury)?;
     START_TIME.save(deps.storage, &start_time)?;
+    EPOCH.save(deps.storage, &Uint128::zero())?;
+    PERIOD.save(deps.storage, &period)?;
+    PERIOD_ANCHOR.save(deps.storage, &(Uint128::zero(), start_time))?;
+    EPOCH_SUPPLY_CONTRACTION_LEFT.save(deps.storage, &Uint128::zero())?;
 
     TOMB_PRICE_ONE.save(deps.storage, &Uint128::from(ETHER))?;
     TOMB_PRICE_CEILING.save(deps.storage, &(Uint128::from(ETHER) * Uint128::from(101u128) / Uint128::from(100u128)));
@@ -208,9 +369,15 @@ pub fn try_initialize(
 
     BOND_SUPPLY_EXPANSION_PERCENT.save(deps.storage, &Uint128::from(500u128))?;
 
+    MAX_DISCOUNT_RATE.save(deps.storage, &(Uint128::from(ETHER) * Uint128::from(130u128) / Uint128::from(100u128)))?;
+    MAX_PREMIUM_RATE.save(deps.storage, &(Uint128::from(ETHER) * Uint128::from(130u128) / Uint128::from(100u128)))?;
+    DISCOUNT_PERCENT.save(deps.storage, &Uint128::zero())?;
     PREMIUM_THRESHOLD.save(deps.storage, &Uint128::from(110u128))?;
     PREMIUM_PERCENT.save(deps.storage, &Uint128::from(7_000u128))?;
-    
+    MINTING_FACTOR_FOR_PAYING_DEBT.save(deps.storage, &Uint128::from(10_000u128))?;
+
+    // no seigniorage recipients until set by the operator
+    SEIGNIORAGE_RECIPIENTS.save(deps.storage, &vec![])?;
 
     // First 12 epochs with 5% expansion
     BOOTSTRAP_EPOCHS.save(deps.storage, &Uint128::from(12u128))?;
@@ -226,17 +393,25 @@ pub fn try_initialize(
     Ok(Response::new())
 }
 
-pub fn try_setoperator(
+pub fn try_set_period(
     deps: DepsMut,
-    env: Env,
     info: MessageInfo,
-    operator: Addr
+    period: Uint128
 )
     -> Result<Response, ContractError>
 {
     check_onlyoperator(deps.storage, info.sender)?;
-    OPERATOR.save(deps.storage, &operator)?;
-    Ok(Response::new().add_attribute("action", "set operator"))
+    check_period(period)?;
+
+    // re-anchor on the end of the current epoch so next_epoch_point does not move
+    let anchor = (EPOCH.load(deps.storage)?, next_epoch_point(deps.storage)?);
+    PERIOD_ANCHOR.save(deps.storage, &anchor)?;
+    let old = PERIOD.load(deps.storage)?;
+    PERIOD.save(deps.storage, &period)?;
+    Ok(Response::new()
+        .add_attribute("action", "set period")
+        .add_attribute("period", period.to_string())
+        .add_event(parameter_change("period", old, period)))
 }
 
 pub fn try_setmasonry(
@@ -248,8 +423,11 @@ pub fn try_setmasonry(
     -> Result<Response, ContractError>
 {
     check_onlyoperator(deps.storage, info.sender)?;
+    let old = MASONRY.load(deps.storage)?;
     MASONRY.save(deps.storage, &masonry)?;
-    Ok(Response::new().add_attribute("action", "set masonry"))
+    Ok(Response::new()
+        .add_attribute("action", "set masonry")
+        .add_event(parameter_change("masonry", old, masonry)))
 }
 
 pub fn try_setbondtreasury(
@@ -261,8 +439,11 @@ pub fn try_setbondtreasury(
     -> Result<Response, ContractError>
 {
     check_onlyoperator(deps.storage, info.sender)?;
+    let old = BOND_TREASURY.load(deps.storage)?;
     BOND_TREASURY.save(deps.storage, &bond_treasury)?;
-    Ok(Response::new().add_attribute("action", "set bond treasury"))
+    Ok(Response::new()
+        .add_attribute("action", "set bond treasury")
+        .add_event(parameter_change("bond_treasury", old, bond_treasury)))
 }
 
 pub fn try_settomboracle(
@@ -274,8 +455,11 @@ pub fn try_settomboracle(
     -> Result<Response, ContractError>
 {
     check_onlyoperator(deps.storage, info.sender)?;
+    let old = TOMB_ORACLE.load(deps.storage)?;
     TOMB_ORACLE.save(deps.storage, &tomb_oracle)?;
-    Ok(Response::new().add_attribute("action", "set tomb oracle"))
+    Ok(Response::new()
+        .add_attribute("action", "set tomb oracle")
+        .add_event(parameter_change("tomb_oracle", old, tomb_oracle)))
 }
 
 pub fn try_settombpriceceiling(
@@ -293,8 +477,11 @@ pub fn try_settombpriceceiling(
         tomb_price_ceiling > tomb_price_one * Uint128::from(120u128) / Uint128::from(100u128) {
             return Err(ContractError::OutofRange {  });
     }
+    let old = TOMB_PRICE_CEILING.load(deps.storage)?;
     TOMB_PRICE_CEILING.save(deps.storage, &tomb_price_ceiling)?;
-    Ok(Response::new().add_attribute("action", "set tomb_price_ceiling"))
+    Ok(Response::new()
+        .add_attribute("action", "set tomb price ceiling")
+        .add_event(parameter_change("tomb_price_ceiling", old, tomb_price_ceiling)))
 }
 
 pub fn try_set_max_supply_expansion_percents(
@@ -311,8 +498,11 @@ pub fn try_set_max_supply_expansion_percents(
         max_supply_expansion_percent > Uint128::from(1000u128) {
             return Err(ContractError::OutofRange {  });
     }
+    let old = MAX_SUPPLY_EXPANSION_PERCENT.load(deps.storage)?;
     MAX_SUPPLY_EXPANSION_PERCENT.save(deps.storage, &max_supply_expansion_percent)?;
-    Ok(Response::new().add_attribute("action", "set tomb_price_ceiling"))
+    Ok(Response::new()
+        .add_attribute("action", "set max supply expansion percent")
+        .add_event(parameter_change("max_supply_expansion_percent", old, max_supply_expansion_percent)))
 }
 
 pub fn try_set_supply_tiers_entry(
@@ -325,18 +515,22 @@ pub fn try_set_supply_tiers_entry(
     -> Result<Response, ContractError>  
 {
     check_onlyoperator(deps.storage, info.sender)?;
-    if index < Uint128::zero() || index >= Uint128::from(9u128){
+    let mut supply_tiers = SUPPLY_TIERS.load(deps.storage)?;
+    let index = index.u128() as usize;
+    if index >= supply_tiers.len() {
         return Err(ContractError::IndexOutOfRange{ });
     }
     
-    let mut supply_tiers = SUPPLY_TIERS.load(deps.storage)?;
-    if value <= supply_tiers[index.u128() as usize - 1] || 
-        value >= supply_tiers[index.u128() as usize + 1] {
+    if (index > 0 && value <= supply_tiers[index - 1]) || 
+        (index + 1 < supply_tiers.len() && value >= supply_tiers[index + 1]) {
         return Err(ContractError::IndexOutOfRange {  })
     }
-    supply_tiers[index.u128() as usize] = value;
+    let old = json_value(Some(&supply_tiers))?;
+    supply_tiers[index] = value;
     SUPPLY_TIERS.save(deps.storage, &supply_tiers)?;
-    Ok(Response::new().add_attribute("action", "set supply tiers entry"))
+    Ok(Response::new()
+        .add_attribute("action", "set supply tiers entry")
+        .add_event(parameter_change("supply_tiers", old, json_value(Some(&supply_tiers))?)))
 }
 
 pub fn try_set_max_expansion_tiers_entry(
@@ -349,18 +543,72 @@ pub fn try_set_max_expansion_tiers_entry(
     -> Result<Response, ContractError>
 {
     check_onlyoperator(deps.storage, info.sender)?;
-    if index < Uint128::zero() || index >= Uint128::from(9u128){
+    let mut max_expansion_tiers = MAX_EXPANSION_TIERS.load(deps.storage)?;
+    let index = index.u128() as usize;
+    if index >= max_expansion_tiers.len() {
         return Err(ContractError::IndexOutOfRange{ });
     }
     
-    if value < Uint128::from(10u128) || value > Uint128::from(1000u128){
+    if value < MIN_EXPANSION_TIER || value > MAX_EXPANSION_TIER {
         return Err(ContractError::ValueOutOfRange {  })
     }
-    let mut max_expansion_tiers = MAX_EXPANSION_TIERS.load(deps.storage)?;
-    max_expansion_tiers[index.u128() as usize] = value;
+    if (index > 0 && value > max_expansion_tiers[index - 1]) ||
+        (index + 1 < max_expansion_tiers.len() && value < max_expansion_tiers[index + 1]) {
+        return Err(ContractError::TreasuryError {
+            msg: "Treasury: expansion caps cannot increase with the supply".to_string()
+        });
+    }
+    let old = json_value(Some(&max_expansion_tiers))?;
+    max_expansion_tiers[index] = value;
     MAX_EXPANSION_TIERS.save(deps.storage,&max_expansion_tiers)?;
 
-    Ok(Response::new().add_attribute("action", "set max expansion tiers entry"))
+    Ok(Response::new()
+        .add_attribute("action", "set max expansion tiers entry")
+        .add_event(parameter_change("max_expansion_tiers", old, json_value(Some(&max_expansion_tiers))?)))
+}
+
+pub fn try_set_supply_tiers(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    tiers: Vec<SupplyTier>
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+    if tiers.is_empty() || tiers.len() > MAX_SUPPLY_TIERS {
+        return Err(ContractError::TreasuryError {
+            msg: format!("Treasury: between 1 and {} supply tiers", MAX_SUPPLY_TIERS)
+        });
+    }
+    if tiers.windows(2).any(|pair| pair[0].supply >= pair[1].supply) {
+        return Err(ContractError::TreasuryError {
+            msg: "Treasury: supply tiers must be strictly ascending".to_string()
+        });
+    }
+    if tiers.iter().any(|tier| tier.max_expansion_percent < MIN_EXPANSION_TIER
+        || tier.max_expansion_percent > MAX_EXPANSION_TIER) {
+        return Err(ContractError::ValueOutOfRange {  });
+    }
+    // a larger supply never allows a larger expansion
+    if tiers.windows(2).any(|pair| pair[0].max_expansion_percent < pair[1].max_expansion_percent) {
+        return Err(ContractError::TreasuryError {
+            msg: "Treasury: expansion caps cannot increase with the supply".to_string()
+        });
+    }
+
+    let supply_tiers: Vec<Uint128> = tiers.iter().map(|tier| tier.supply).collect();
+    let max_expansion_tiers: Vec<Uint128> = tiers.iter().map(|tier| tier.max_expansion_percent).collect();
+    let old_supply_tiers = json_value(SUPPLY_TIERS.may_load(deps.storage)?.as_ref())?;
+    let old_max_expansion_tiers = json_value(MAX_EXPANSION_TIERS.may_load(deps.storage)?.as_ref())?;
+    SUPPLY_TIERS.save(deps.storage, &supply_tiers)?;
+    MAX_EXPANSION_TIERS.save(deps.storage, &max_expansion_tiers)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set supply tiers")
+        .add_attribute("tiers", tiers.len().to_string())
+        .add_event(parameter_change("supply_tiers", old_supply_tiers, json_value(Some(&supply_tiers))?))
+        .add_event(parameter_change("max_expansion_tiers", old_max_expansion_tiers, json_value(Some(&max_expansion_tiers))?)))
 }
 
 pub fn try_set_bond_depletion_floor_percent(
@@ -376,8 +624,11 @@ pub fn try_set_bond_depletion_floor_percent(
         || bond_depletion_floor_percent > Uint128::from(10_000u128){
         return Err(ContractError::ValueOutOfRange {  });
     }
+    let old = BOND_DEPLETION_FLOOR_PERCENT.load(deps.storage)?;
     BOND_DEPLETION_FLOOR_PERCENT.save(deps.storage, &bond_depletion_floor_percent)?;
-    Ok(Response::new().add_attribute("action", "set bond deplection floor percent"))
+    Ok(Response::new()
+        .add_attribute("action", "set bond depletion floor percent")
+        .add_event(parameter_change("bond_depletion_floor_percent", old, bond_depletion_floor_percent)))
 }
 
 pub fn try_set_max_supply_contraction_percent(
@@ -393,8 +644,11 @@ pub fn try_set_max_supply_contraction_percent(
         || max_supply_contraction_percent > Uint128::from(1_500u128){
         return Err(ContractError::ValueOutOfRange {  });
     }
+    let old = MAX_SUPPLY_CONTRACTION_PERCENT.load(deps.storage)?;
     MAX_SUPPLY_CONTRACTION_PERCENT.save(deps.storage, &max_supply_contraction_percent)?;
-    Ok(Response::new().add_attribute("action", "set max supply contraction percent"))
+    Ok(Response::new()
+        .add_attribute("action", "set max supply contraction percent")
+        .add_event(parameter_change("max_supply_contraction_percent", old, max_supply_contraction_percent)))
 }
 
 pub fn try_set_max_debt_ratio_percent(
@@ -410,8 +664,11 @@ pub fn try_set_max_debt_ratio_percent(
         || max_debt_ratio_percent > Uint128::from(10_000u128){
         return Err(ContractError::ValueOutOfRange {  });
     }
+    let old = MAX_DEBT_RATIO_PERCENT.load(deps.storage)?;
     MAX_DEBT_RATIO_PERCENT.save(deps.storage, &max_debt_ratio_percent)?;
-    Ok(Response::new().add_attribute("action", "set max debt ratio percent"))
+    Ok(Response::new()
+        .add_attribute("action", "set max debt ratio percent")
+        .add_event(parameter_change("max_debt_ratio_percent", old, max_debt_ratio_percent)))
 }
 
 pub fn try_set_bootstrap(
@@ -431,38 +688,49 @@ pub fn try_set_bootstrap(
         bootstrap_supply_expansion_percent > Uint128::from(1_000u128){
         return Err(ContractError::ValueOutOfRange {  });
     }
+    let old_epochs = BOOTSTRAP_EPOCHS.load(deps.storage)?;
+    let old_percent = BOOTSTRAP_SUPPLY_EXPANSION_PERCENT.load(deps.storage)?;
     BOOTSTRAP_EPOCHS.save(deps.storage, &bootstrap_epochs)?;
     BOOTSTRAP_SUPPLY_EXPANSION_PERCENT.save(deps.storage, &bootstrap_supply_expansion_percent)?;
 
-    Ok(Response::new().add_attribute("action", "set bootstrap"))
+    Ok(Response::new()
+        .add_attribute("action", "set bootstrap")
+        .add_event(parameter_change("bootstrap_epochs", old_epochs, bootstrap_epochs))
+        .add_event(parameter_change("bootstrap_supply_expansion_percent", old_percent, bootstrap_supply_expansion_percent)))
 }
 
 
-pub fn try_set_extra_funds(
+pub fn try_set_seigniorage_recipients(
     deps: DepsMut,
-    env: Env,
     info: MessageInfo,
-    dao_fund: Addr,
-    dao_fund_shared_percent: Uint128,
-    dev_fund: Addr,
-    dev_fund_shared_percent: Uint128
+    recipients: Vec<SeigniorageRecipient>
 )
     -> Result<Response, ContractError>
 {
     check_onlyoperator(deps.storage, info.sender)?;
-    if dao_fund == Addr::unchecked("".to_string()) ||
-        dev_fund == Addr::unchecked("".to_string()) {
-        return Err(ContractError::ZeroAddress {  });
+    if recipients.len() > MAX_SEIGNIORAGE_RECIPIENTS {
+        return Err(ContractError::TreasuryError {
+            msg: format!("Treasury: at most {} seigniorage recipients", MAX_SEIGNIORAGE_RECIPIENTS)
+        });
     }
-    if dao_fund_shared_percent > Uint128::from(3_000u128) ||
-        dev_fund_shared_percent > Uint128::from(1_000u128) {
+    for (index, recipient) in recipients.iter().enumerate() {
+        deps.api.addr_validate(recipient.address.as_str())?;
+        if recipient.name.is_empty() || recipients[..index].iter().any(|other| other.name == recipient.name) {
+            return Err(ContractError::TreasuryError {
+                msg: "Treasury: recipient names must be unique and not empty".to_string()
+            });
+        }
+    }
+    let total_share = recipients.iter().fold(Uint128::zero(), |total, recipient| total + recipient.share);
+    if recipients.iter().any(|recipient| recipient.share.is_zero()) || total_share > MAX_SEIGNIORAGE_RECIPIENTS_SHARE {
         return Err(ContractError::ValueOutOfRange {  });
     }
-    DAOFUND.save(deps.storage, &dao_fund)?;
-    DAOFUND_SHARED_PERCENT.save(deps.storage, &dao_fund_shared_percent)?;
-    DEVFUND.save(deps.storage, &dev_fund)?;
-    DEVFUND_SHARED_PERCENT.save(deps.storage, &dev_fund_shared_percent)?;
-    Ok(Response::new().add_attribute("action", "set extra funds"))
+
+    let old = json_value(SEIGNIORAGE_RECIPIENTS.may_load(deps.storage)?.as_ref())?;
+    SEIGNIORAGE_RECIPIENTS.save(deps.storage, &recipients)?;
+    Ok(Response::new()
+        .add_attribute("action", "set seigniorage recipients")
+        .add_event(parameter_change("seigniorage_recipients", old, json_value(Some(&recipients))?)))
 }
 
 pub fn try_set_max_discount_rate(
@@ -474,8 +742,11 @@ pub fn try_set_max_discount_rate(
     -> Result<Response, ContractError>
 {
     check_onlyoperator(deps.storage, info.sender)?;
+    let old = MAX_DISCOUNT_RATE.load(deps.storage)?;
     MAX_DISCOUNT_RATE.save(deps.storage, &max_discount_rate)?;
-    Ok(Response::new().add_attribute("action", "set max discount rate"))
+    Ok(Response::new()
+        .add_attribute("action", "set max discount rate")
+        .add_event(parameter_change("max_discount_rate", old, max_discount_rate)))
 }
 
 pub fn try_set_max_premium_rate(
@@ -487,8 +758,11 @@ pub fn try_set_max_premium_rate(
     -> Result<Response, ContractError>
 {
     check_onlyoperator(deps.storage, info.sender)?;
+    let old = MAX_PREMIUM_RATE.load(deps.storage)?;
     MAX_PREMIUM_RATE.save(deps.storage, &max_premium_rate)?;
-    Ok(Response::new().add_attribute("action", "set max premium rate"))
+    Ok(Response::new()
+        .add_attribute("action", "set max premium rate")
+        .add_event(parameter_change("max_premium_rate", old, max_premium_rate)))
 }
 
 pub fn try_set_discount_percent(
@@ -503,8 +777,11 @@ pub fn try_set_discount_percent(
     if discount_percent > Uint128::from(20_000u128){
         return Err(ContractError::ValueOutOfRange {  });
     }
+    let old = DISCOUNT_PERCENT.load(deps.storage)?;
     DISCOUNT_PERCENT.save(deps.storage, &discount_percent)?;
-    Ok(Response::new().add_attribute("action", "set discount percent"))
+    Ok(Response::new()
+        .add_attribute("action", "set discount percent")
+        .add_event(parameter_change("discount_percent", old, discount_percent)))
 }
 
 pub fn try_set_premium_threshold(
@@ -521,8 +798,11 @@ pub fn try_set_premium_threshold(
     {
         return Err(ContractError::ValueOutOfRange {  });
     }
+    let old = PREMIUM_THRESHOLD.load(deps.storage)?;
     PREMIUM_THRESHOLD.save(deps.storage, &premium_threshold)?;
-    Ok(Response::new().add_attribute("action", "set premium threshold"))
+    Ok(Response::new()
+        .add_attribute("action", "set premium threshold")
+        .add_event(parameter_change("premium_threshold", old, premium_threshold)))
 }
 
 
@@ -538,8 +818,11 @@ pub fn try_set_premium_percent(
     if premium_percent > Uint128::from(20_000u128){
         return Err(ContractError::ValueOutOfRange {  });
     }
+    let old = PREMIUM_PERCENT.load(deps.storage)?;
     PREMIUM_PERCENT.save(deps.storage, &premium_percent)?;
-    Ok(Response::new().add_attribute("action", "set premium percent"))
+    Ok(Response::new()
+        .add_attribute("action", "set premium percent")
+        .add_event(parameter_change("premium_percent", old, premium_percent)))
 }
 
 pub fn try_set_minting_factor_for_paying_debt(
@@ -555,8 +838,11 @@ pub fn try_set_minting_factor_for_paying_debt(
         minting_factor_for_paying_debt > Uint128::from(20_000u128){
         return Err(ContractError::ValueOutOfRange {  });
     }
+    let old = MINTING_FACTOR_FOR_PAYING_DEBT.load(deps.storage)?;
     MINTING_FACTOR_FOR_PAYING_DEBT.save(deps.storage, &minting_factor_for_paying_debt)?;
-    Ok(Response::new().add_attribute("action", "set mingting factor for paying debt"))
+    Ok(Response::new()
+        .add_attribute("action", "set minting factor for paying debt")
+        .add_event(parameter_change("minting_factor_for_paying_debt", old, minting_factor_for_paying_debt)))
 }
 
 pub fn try_set_bond_supply_expansion_percent(
@@ -568,96 +854,438 @@ pub fn try_set_bond_supply_expansion_percent(
     -> Result<Response, ContractError>
 {
     check_onlyoperator(deps.storage, info.sender)?;
+    let old = BOND_SUPPLY_EXPANSION_PERCENT.load(deps.storage)?;
     BOND_SUPPLY_EXPANSION_PERCENT.save(deps.storage, &bond_supply_expansion_percent)?;
-    Ok(Response::new().add_attribute("action", "set bond supply expansion percent"))
+    Ok(Response::new()
+        .add_attribute("action", "set bond supply expansion percent")
+        .add_event(parameter_change("bond_supply_expansion_percent", old, bond_supply_expansion_percent)))
 }
 
-pub fn try_update_tomb_price(
+pub fn try_add_excluded_address(
     deps: DepsMut,
-    env: Env,
-    info: MessageInfo
-) -> Result<Response, ContractError> {
+    info: MessageInfo,
+    address: Addr
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+    let address = deps.api.addr_validate(address.as_str())?;
 
-    let msg_update = WasmMsg::Execute {
-        contract_addr: TOMB_ORACLE.load(deps.storage)?.to_string(),
-        msg: to_binary(
-            &OracleMsg::Update {  }
-        ).unwrap(),
-        funds: vec![]
+    let mut excluded_from_total_supply = EXCLUDED_FROM_TOTALSUPPLY.load(deps.storage)?;
+    if excluded_from_total_supply.contains(&address) {
+        return Err(ContractError::TreasuryError{
+            msg: "Treasury: address already excluded".to_string()
+        });
+    }
+    if excluded_from_total_supply.len() >= MAX_EXCLUDED_FROM_TOTALSUPPLY {
+        return Err(ContractError::TreasuryError{
+            msg: "Treasury: too many excluded addresses".to_string()
+        });
+    }
+    let old = json_value(Some(&excluded_from_total_supply))?;
+    excluded_from_total_supply.push(address.clone());
+    EXCLUDED_FROM_TOTALSUPPLY.save(deps.storage, &excluded_from_total_supply)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add excluded address")
+        .add_attribute("address", address.to_string())
+        .add_event(parameter_change("excluded_from_total_supply", old, json_value(Some(&excluded_from_total_supply))?)))
+}
+
+pub fn try_remove_excluded_address(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: Addr
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+
+    let mut excluded_from_total_supply = EXCLUDED_FROM_TOTALSUPPLY.load(deps.storage)?;
+    let old = json_value(Some(&excluded_from_total_supply))?;
+    let len = excluded_from_total_supply.len();
+    excluded_from_total_supply.retain(|excluded| *excluded != address);
+    if excluded_from_total_supply.len() == len {
+        return Err(ContractError::TreasuryError{
+            msg: "Treasury: address not excluded".to_string()
+        });
+    }
+    EXCLUDED_FROM_TOTALSUPPLY.save(deps.storage, &excluded_from_total_supply)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove excluded address")
+        .add_attribute("address", address.to_string())
+        .add_event(parameter_change("excluded_from_total_supply", old, json_value(Some(&excluded_from_total_supply))?)))
+}
+
+pub fn try_set_keeper_bounty(
+    deps: DepsMut,
+    info: MessageInfo,
+    bounty: Option<KeeperBounty>
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+    let out_of_range = match &bounty {
+        Some(KeeperBounty::Fixed { amount }) => *amount > MAX_KEEPER_BOUNTY,
+        Some(KeeperBounty::Percent { percent, cap }) => *percent > MAX_KEEPER_BOUNTY_PERCENT || *cap > MAX_KEEPER_BOUNTY,
+        None => false
     };
+    if out_of_range {
+        return Err(ContractError::ValueOutOfRange {  });
+    }
+    let event = parameter_change("keeper_bounty", json_value(KEEPER_BOUNTY.may_load(deps.storage)?.as_ref())?, json_value(bounty.as_ref())?);
+    match bounty {
+        Some(bounty) => KEEPER_BOUNTY.save(deps.storage, &bounty)?,
+        None => KEEPER_BOUNTY.remove(deps.storage)
+    }
     Ok(Response::new()
-        .add_attribute("action", "Oracle Update")
-        .add_message(msg_update)
-    )
+        .add_attribute("action", "set keeper bounty")
+        .add_event(event))
 }
 
-pub fn try_buy_bonds(
+pub fn try_set_catch_up_policy(
     deps: DepsMut,
-    env: Env,
     info: MessageInfo,
-    tomb_amount: Uint128,
-    target_price: Uint128
+    policy: Option<CatchUpPolicy>
 )
     -> Result<Response, ContractError>
 {
-    check_condition(deps.storage, env.clone())?;
-    check_operator(deps.storage, deps.querier, env.clone())?;
+    check_onlyoperator(deps.storage, info.sender)?;
+    let event = parameter_change("catch_up_policy", json_value(CATCH_UP_POLICY.may_load(deps.storage)?.as_ref())?, json_value(policy.as_ref())?);
+    match policy {
+        Some(CatchUpPolicy::CapPerBlock { max_epochs: 0 }) => {
+            return Err(ContractError::ValueOutOfRange {  });
+        },
+        Some(policy) => CATCH_UP_POLICY.save(deps.storage, &policy)?,
+        None => CATCH_UP_POLICY.remove(deps.storage)
+    }
+    Ok(Response::new()
+        .add_attribute("action", "set catch up policy")
+        .add_event(event))
+}
 
-    if tomb_amount <= Uint128::zero() {
-        return Err(ContractError::ZeroValue {  });
+pub fn try_set_bond_pricing(
+    deps: DepsMut,
+    info: MessageInfo,
+    pricing: BondPricing
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+    match &pricing {
+        BondPricing::Linear {} => {},
+        BondPricing::PiecewiseLinear { discount, premium } => {
+            let tomb_price_one = TOMB_PRICE_ONE.load(deps.storage)?;
+            for points in [discount, premium] {
+                if points.is_empty() || points.len() > MAX_PRICE_POINTS {
+                    return Err(ContractError::TreasuryError {
+                        msg: format!("Treasury: between 1 and {} price points", MAX_PRICE_POINTS)
+                    });
+                }
+                if points.windows(2).any(|pair| pair[0].price >= pair[1].price) {
+                    return Err(ContractError::TreasuryError {
+                        msg: "Treasury: price points must be strictly ascending".to_string()
+                    });
+                }
+                if points.iter().any(|point| point.rate < tomb_price_one) {
+                    return Err(ContractError::ValueOutOfRange {  });
+                }
+            }
+            if discount.iter().any(|point| point.price > tomb_price_one)
+                || premium.iter().any(|point| point.price <= tomb_price_one) {
+                return Err(ContractError::ValueOutOfRange {  });
+            }
+        },
+        BondPricing::DebtRatio { taper_from_percent } => {
+            if *taper_from_percent > Uint128::from(10_000u128) {
+                return Err(ContractError::ValueOutOfRange {  });
+            }
+        }
     }
+    let event = parameter_change("bond_pricing", json_value(Some(&get_bond_pricing(deps.storage)?))?, json_value(Some(&pricing))?);
+    BOND_PRICING.save(deps.storage, &pricing)?;
+    Ok(Response::new()
+        .add_attribute("action", "set bond pricing")
+        .add_event(event))
+}
 
-    let tomb_price = get_tomb_price(deps.storage, &deps.querier)?;
-    if tomb_price == target_price {
-        return Err(ContractError::TreasuryError { 
-            msg: "tomb price moved".to_string()
-        });
+pub fn try_set_circuit_breaker(
+    deps: DepsMut,
+    info: MessageInfo,
+    breaker: Option<CircuitBreaker>
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+    let event = parameter_change("circuit_breaker", json_value(CIRCUIT_BREAKER.may_load(deps.storage)?.as_ref())?, json_value(breaker.as_ref())?);
+    match breaker {
+        Some(breaker) if breaker.max_price_move < MIN_BREAKER_PRICE_MOVE || breaker.max_price_move > MAX_BREAKER_PRICE_MOVE
+            || breaker.tripped_expansion_percent > MAX_EXPANSION_TIER
+            || breaker.reanchor_after < MIN_BREAKER_REANCHOR_AFTER || breaker.reanchor_after > MAX_BREAKER_REANCHOR_AFTER => {
+            return Err(ContractError::ValueOutOfRange {  });
+        },
+        Some(breaker) => CIRCUIT_BREAKER.save(deps.storage, &breaker)?,
+        None => CIRCUIT_BREAKER.remove(deps.storage)
     }
+    Ok(Response::new()
+        .add_attribute("action", "set circuit breaker")
+        .add_event(event))
+}
 
-    if tomb_price >= TOMB_PRICE_ONE.load(deps.storage)? {
-        return Err(ContractError::TreasuryError { 
-            msg: "tombPrice not eligible for bond purchase".to_string() 
-        });
+pub fn try_set_timelock_delay(
+    deps: DepsMut,
+    info: MessageInfo,
+    delay: Option<Uint128>
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+    let event = parameter_change("timelock_delay", optional_value(TIMELOCK_DELAY.may_load(deps.storage)?), optional_value(delay));
+    match delay {
+        Some(delay) if delay < MIN_TIMELOCK_DELAY || delay > MAX_TIMELOCK_DELAY => {
+            return Err(ContractError::ValueOutOfRange {  });
+        },
+        Some(delay) => TIMELOCK_DELAY.save(deps.storage, &delay)?,
+        None => TIMELOCK_DELAY.remove(deps.storage)
     }
+    Ok(Response::new()
+        .add_attribute("action", "set timelock delay")
+        .add_event(event))
+}
 
-    if tomb_amount > EPOCH_SUPPLY_CONTRACTION_LEFT.load(deps.storage)? {
-        return Err(ContractError::TreasuryError { 
-            msg: "not enough bond left to purchase".to_string() 
+pub fn try_queue_transaction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+    let delay = TIMELOCK_DELAY.may_load(deps.storage)?.ok_or(ContractError::TreasuryError {
+        msg: "Treasury: timelock is not enabled".to_string()
+    })?;
+    if !is_timelocked(&msg) {
+        return Err(ContractError::TreasuryError {
+            msg: "Treasury: only setters can be queued".to_string()
         });
     }
 
-    let rate = get_bond_discount_rate(deps.storage, &deps.querier)?;
-    if rate <= Uint128::zero() {
-        return Err(ContractError::TreasuryError { msg: "invalid bond rate".to_string() });
-    }
+    let id = NEXT_TRANSACTION_ID.may_load(deps.storage)?.unwrap_or_default();
+    let eta = Uint128::from(env.block.time.seconds()) + delay;
+    let transaction = QueuedTransaction { id, msg, eta };
+    QUEUED_TRANSACTIONS.save(deps.storage, U64Key::from(id), &transaction)?;
+    NEXT_TRANSACTION_ID.save(deps.storage, &(id + 1))?;
 
-    let bond_amount = tomb_amount * rate / Uint128::from(ETHER);
-    let tomb_supply = get_tomb_circulating_supply(deps.storage, &deps.querier)?;
-    let tbond_total_supply = get_total_supply(&deps.querier, TBOND.load(deps.storage)?)?;
-    let new_bond_supply = tbond_total_supply + bond_amount;
-    let max_debt_ratio_percent = MAX_DEBT_RATIO_PERCENT.load(deps.storage)?;
+    Ok(Response::new()
+        .add_attribute("action", "queue transaction")
+        .add_attribute("id", id.to_string())
+        .add_attribute("eta", eta.to_string())
+        .add_event(timelock_transaction("queued", &transaction)?))
+}
 
-    if new_bond_supply > tomb_supply * max_debt_ratio_percent / Uint128::from(10_000u128) {
+pub fn try_execute_transaction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender.clone())?;
+    let transaction = load_queued_transaction(deps.storage, id)?;
+    let now = Uint128::from(env.block.time.seconds());
+    if now < transaction.eta {
         return Err(ContractError::TreasuryError {
-            msg: "over max debt ratio".to_string() 
+            msg: "Treasury: transaction is still timelocked".to_string()
         });
     }
+    QUEUED_TRANSACTIONS.remove(deps.storage, U64Key::from(id));
+    // an expired transaction is dropped instead of executed, an error would keep it queued forever
+    if now > transaction.eta + TIMELOCK_GRACE_PERIOD {
+        return Ok(Response::new()
+            .add_attribute("action", "expire transaction")
+            .add_attribute("id", id.to_string())
+            .add_event(timelock_transaction("expired", &transaction)?));
+    }
 
-    let msg_burnfrom = WasmMsg::Execute { 
-        contract_addr: TOMB.load(deps.storage)?.to_string(), 
+    // the response of the setter, tagged with the transaction
+    let event = timelock_transaction("executed", &transaction)?;
+    let res = dispatch(deps, env, info, transaction.msg)?;
+    Ok(res.add_attribute("executed_transaction", id.to_string()).add_event(event))
+}
+
+pub fn try_cancel_transaction(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+    let transaction = load_queued_transaction(deps.storage, id)?;
+    QUEUED_TRANSACTIONS.remove(deps.storage, U64Key::from(id));
+    Ok(Response::new()
+        .add_attribute("action", "cancel transaction")
+        .add_attribute("id", id.to_string())
+        .add_event(timelock_transaction("cancelled", &transaction)?))
+}
+
+pub fn try_set_guardian(
+    deps: DepsMut,
+    info: MessageInfo,
+    guardian: Option<Addr>
+)
+    -> Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+    let event = parameter_change("guardian", optional_value(GUARDIAN.may_load(deps.storage)?), optional_value(guardian.clone()));
+    Ok(PAUSE.set_guardian(deps.storage, guardian)?.add_event(event))
+}
+
+fn load_queued_transaction(storage: &dyn Storage, id: u64) -> Result<QueuedTransaction, ContractError> {
+    QUEUED_TRANSACTIONS.may_load(storage, U64Key::from(id))?.ok_or(ContractError::TreasuryError {
+        msg: "Treasury: unknown transaction".to_string()
+    })
+}
+
+pub fn try_update_tomb_price(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo
+) -> Result<Response, ContractError> {
+    Ok(Response::new()
+        .add_attribute("action", "Oracle Update")
+        .add_submessage(update_tomb_price(deps.storage)?)
+    )
+}
+
+// The Oracle refuses early updates, which must not block the Treasury (try/catch of _updateTombPrice)
+fn update_tomb_price(storage: &dyn Storage) -> StdResult<SubMsg> {
+    let msg_update = WasmMsg::Execute {
+        contract_addr: TOMB_ORACLE.load(storage)?.to_string(),
         msg: to_binary(
-            &BasisAssetMsg::BurnFrom{
-                    from: info.sender.to_string(),
-                    amount: tomb_amount
-                }
-            )?,
+            &OracleMsg::Update {  }
+        )?,
+        funds: vec![]
+    };
+    Ok(SubMsg::reply_on_error(msg_update, UPDATE_TOMB_PRICE_REPLY_ID))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(
+    _deps: DepsMut,
+    _env: Env,
+    msg: Reply
+) -> Result<Response, ContractError> {
+    match msg.id {
+        UPDATE_TOMB_PRICE_REPLY_ID => Ok(Response::new()
+            .add_attribute("action", "Oracle Update skipped")),
+        _ => Err(ContractError::TreasuryError {
+            msg: format!("unknown reply id {}", msg.id)
+        })
+    }
+}
+
+// folds the messages and attributes of an internal step into the response
+fn compose(res: Response, step: Response) -> Response {
+    res.add_submessages(step.messages)
+        .add_attributes(step.attributes)
+        .add_events(step.events)
+}
+
+// Where the TOMB or TBOND burnt by a bond trade comes from
+enum Payment {
+    // BurnFrom the allowance of the trader
+    Allowance,
+    // sent to the Treasury with a Cw20HookMsg
+    Received,
+}
+
+fn burn_msg(token: Addr, trader: &Addr, amount: Uint128, payment: Payment) -> StdResult<WasmMsg> {
+    let msg = match payment {
+        Payment::Allowance => BasisAssetMsg::BurnFrom { from: trader.to_string(), amount },
+        Payment::Received => BasisAssetMsg::Burn { amount }
+    };
+    Ok(WasmMsg::Execute {
+        contract_addr: token.to_string(),
+        msg: to_binary(&msg)?,
         funds: vec![]
+    })
+}
+
+pub fn try_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Cw20ReceiveMsg
+)
+    -> Result<Response, ContractError>
+{
+    let trader = deps.api.addr_validate(&msg.sender)?;
+    match from_binary(&msg.msg)? {
+        Cw20HookMsg::BuyBonds { target_price } => {
+            if info.sender != TOMB.load(deps.storage)? {
+                return Err(ContractError::TreasuryError {
+                    msg: "Treasury: bonds are bought with TOMB".to_string()
+                });
+            }
+            buy_bonds(deps, env, trader, msg.amount, target_price, Payment::Received)
+        },
+        Cw20HookMsg::RedeemBonds { target_price } => {
+            if info.sender != TBOND.load(deps.storage)? {
+                return Err(ContractError::TreasuryError {
+                    msg: "Treasury: bonds are redeemed with TBOND".to_string()
+                });
+            }
+            redeem_bonds(deps, env, trader, msg.amount, target_price, Payment::Received)
+        }
+    }
+}
+
+pub fn try_buy_bonds(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tomb_amount: Uint128,
+    target_price: Uint128
+)
+    -> Result<Response, ContractError>
+{
+    buy_bonds(deps, env, info.sender, tomb_amount, target_price, Payment::Allowance)
+}
+
+fn buy_bonds(
+    deps: DepsMut,
+    env: Env,
+    buyer: Addr,
+    tomb_amount: Uint128,
+    target_price: Uint128,
+    payment: Payment
+)
+    -> Result<Response, ContractError>
+{
+    check_operator(deps.storage, deps.querier, env.clone())?;
+
+    // a higher price means fewer bonds for the same TOMB
+    if get_tomb_price(deps.storage, &deps.querier)? > target_price {
+        return Err(ContractError::TreasuryError { 
+            msg: "tomb price moved".to_string()
+        });
+    }
+
+    let (bond_amount, tomb_price, rate) = match quote_buy_bonds(deps.storage, &deps.querier, &env, tomb_amount)? {
+        BondQuote::Amount { amount, tomb_price, rate } => (amount, tomb_price, rate),
+        BondQuote::Unavailable { reason } => return Err(bond_quote_error(reason, Operation::BuyBonds))
     };
+
+    let msg_burn = burn_msg(TOMB.load(deps.storage)?, &buyer, tomb_amount, payment)?;
     let msg_mint = WasmMsg::Execute { 
-        contract_addr: TOMB.load(deps.storage)?.to_string(), 
+        contract_addr: TBOND.load(deps.storage)?.to_string(), 
         msg: to_binary(
             &BasisAssetMsg::Mint { 
-                recipient: info.sender.to_string(), 
+                recipient: buyer.to_string(), 
                 amount: bond_amount 
                 }
             )?,
@@ -668,18 +1296,38 @@ pub fn try_buy_bonds(
     epoch_supply_contraction_left -= tomb_amount;
     EPOCH_SUPPLY_CONTRACTION_LEFT.save(deps.storage, &epoch_supply_contraction_left)?;
 
-    let mut _deps = deps;
-    let _env = env.clone();
-    let _info = info.clone();
-
-    execute(_deps.branch(), _env, _info, ExecuteMsg::UpdateTombPrice {  })?;
-
     Ok(Response::new()
         .add_attribute("action", "buy bonds")
-        .add_messages([msg_burnfrom, msg_mint])
+        .add_attribute("tomb_amount", tomb_amount.to_string())
+        .add_attribute("bond_amount", bond_amount.to_string())
+        .add_event(Event::new("bonds_bought")
+            .add_attribute("sender", buyer.to_string())
+            .add_attribute("tomb_amount", tomb_amount.to_string())
+            .add_attribute("bond_amount", bond_amount.to_string())
+            .add_attribute("tomb_price", tomb_price.to_string())
+            .add_attribute("discount_rate", rate.to_string()))
+        .add_messages([msg_burn, msg_mint])
+        .add_submessage(update_tomb_price(deps.storage)?)
+        .set_data(to_binary(&bond_amount)?)
     )
 }
 
+// the errors bond trades returned before the quotes
+fn bond_quote_error(reason: BondQuoteFailure, operation: Operation) -> ContractError {
+    let msg = match reason {
+        BondQuoteFailure::Paused {} => return ContractError::Pause(PauseError::Paused {}),
+        BondQuoteFailure::NotStartedYet {} => return ContractError::NotStartedYet {},
+        BondQuoteFailure::ZeroAmount {} if operation == Operation::BuyBonds => return ContractError::ZeroValue {},
+        BondQuoteFailure::ZeroAmount {} => "can not redeem bonds with zero amount",
+        BondQuoteFailure::PriceNotEligible { .. } => "tombPrice not eligible for bond purchase",
+        BondQuoteFailure::InvalidRate {} => "invalid bond rate",
+        BondQuoteFailure::NotEnoughContractionLeft { .. } => "not enough bond left to purchase",
+        BondQuoteFailure::OverMaxDebtRatio { .. } => "over max debt ratio",
+        BondQuoteFailure::NotEnoughBudget { .. } => "Treasury: treasury has no more budget"
+    };
+    ContractError::TreasuryError { msg: msg.to_string() }
+}
+
 pub fn try_redeem_bonds(
     deps: DepsMut,
     env: Env,
@@ -689,39 +1337,32 @@ pub fn try_redeem_bonds(
 )
     ->Result<Response, ContractError>
 {
-    check_condition(deps.storage, env.clone())?;
-    check_operator(deps.storage, deps.querier, env.clone())?;
+    redeem_bonds(deps, env, info.sender, bond_amount, target_price, Payment::Allowance)
+}
 
-    if bond_amount <= Uint128::zero() {
-        return Err(ContractError::TreasuryError { msg: "can not redeem bonds with zero amount".to_string()});
-    }
+fn redeem_bonds(
+    deps: DepsMut,
+    env: Env,
+    redeemer: Addr,
+    bond_amount: Uint128,
+    target_price: Uint128,
+    payment: Payment
+)
+    ->Result<Response, ContractError>
+{
+    check_operator(deps.storage, deps.querier, env.clone())?;
 
-    let tomb_price = get_tomb_price(deps.storage, &deps.querier)?;
-    if tomb_price == target_price {
+    // a lower price means less TOMB for the same bonds
+    if get_tomb_price(deps.storage, &deps.querier)? < target_price {
         return Err(ContractError::TreasuryError { 
             msg: "tomb price moved".to_string()
         });
     }
 
-    if tomb_price <= TOMB_PRICE_ONE.load(deps.storage)? {
-        return Err(ContractError::TreasuryError { 
-            msg: "tombPrice not eligible for bond purchase".to_string() 
-        });
-    }
-
-    let rate = get_bond_premium_rate(deps.storage, &deps.querier)?;
-    if rate <= Uint128::zero() {
-        return Err(ContractError::TreasuryError { msg: "invalid bond rate".to_string() });
-    }
-
-    let tomb_amount = bond_amount * rate / Uint128::from(ETHER);
-    let tomb = TOMB.load(deps.storage)?;
-    let tomb_balance = query_token_balance(&deps.querier, tomb, env.clone().contract.address)?;
-    if tomb_balance < tomb_amount {
-        return Err(ContractError::TreasuryError { 
-            msg: "Treasury: treasury has no more budget".to_string()
-        });
-    }
+    let (tomb_amount, tomb_price, rate) = match quote_redeem_bonds(deps.storage, &deps.querier, &env, bond_amount)? {
+        BondQuote::Amount { amount, tomb_price, rate } => (amount, tomb_price, rate),
+        BondQuote::Unavailable { reason } => return Err(bond_quote_error(reason, Operation::RedeemBonds))
+    };
     let mut seigniorage_saved = SEIGNIORAGE_SAVED.load(deps.storage)?;
     if seigniorage_saved > tomb_amount {
         seigniorage_saved -= tomb_amount
@@ -730,39 +1371,32 @@ pub fn try_redeem_bonds(
     }
     SEIGNIORAGE_SAVED.save(deps.storage, &seigniorage_saved)?;
 
-    let msg_burnfrom = WasmMsg::Execute { 
-        contract_addr: TBOND.load(deps.storage)?.to_string(), 
-        msg: to_binary(
-            &BasisAssetMsg::BurnFrom{
-                    from: info.sender.to_string(),
-                    amount: bond_amount
-                }
-            )?,
-        funds: vec![]
-    };
+    let msg_burn = burn_msg(TBOND.load(deps.storage)?, &redeemer, bond_amount, payment)?;
     let msg_transfer = WasmMsg::Execute { 
         contract_addr: TOMB.load(deps.storage)?.to_string(), 
         msg: to_binary(
             &BasisAssetMsg::Transfer {  
-                recipient: info.sender.to_string(), 
+                recipient: redeemer.to_string(), 
                 amount: tomb_amount 
                 }
             )?,
         funds: vec![]
     };
 
-    let mut _deps = deps;
-    let _env = env.clone();
-    let _info = info.clone();
-
-    execute(_deps.branch(), _env, _info, ExecuteMsg::UpdateTombPrice {  })?;
-
     Ok(Response::new()
         .add_attribute("action", "redeem bonds")
-        .add_messages([msg_burnfrom, msg_transfer])
+        .add_event(Event::new("bonds_redeemed")
+            .add_attribute("sender", redeemer.to_string())
+            .add_attribute("bond_amount", bond_amount.to_string())
+            .add_attribute("tomb_amount", tomb_amount.to_string())
+            .add_attribute("tomb_price", tomb_price.to_string())
+            .add_attribute("premium_rate", rate.to_string()))
+        .add_messages([msg_burn, msg_transfer])
+        .add_submessage(update_tomb_price(deps.storage)?)
     )
 }
 
+// only the Treasury itself can move seigniorage, see try_allocate_seigniorage
 pub fn try_send_to_masonry(
     deps: DepsMut,
     env: Env,
@@ -770,14 +1404,25 @@ pub fn try_send_to_masonry(
     amount: Uint128
 )
     ->Result<Response, ContractError>
+{
+    check_onlyself(&env, info.sender)?;
+    Ok(send_to_masonry(deps.storage, &env, amount)?)
+}
+
+fn send_to_masonry(
+    storage: &mut dyn Storage,
+    env: &Env,
+    amount: Uint128
+)
+    -> StdResult<Response>
 {
     let mut msgs: Vec<CosmosMsg> = Vec::new();
-    let tomb = TOMB.load(deps.storage)?;
+    let tomb = TOMB.load(storage)?;
 
     let msg_mint = WasmMsg::Execute { 
         contract_addr: tomb.to_string(), 
         msg: to_binary(
-            &BasisAssetMsg::Transfer {  
+            &BasisAssetMsg::Mint {  
                 recipient: env.contract.address.to_string(), 
                 amount: amount 
                 }
@@ -786,49 +1431,30 @@ pub fn try_send_to_masonry(
     };
     msgs.push(CosmosMsg::Wasm(msg_mint));
 
-    let mut daofund_shared_amount = Uint128::zero();
-    let daofund_shared_percent = DAOFUND_SHARED_PERCENT.load(deps.storage)?;
-    if daofund_shared_percent > Uint128::zero() {
-        daofund_shared_amount = amount * daofund_shared_percent / Uint128::from(10_000u128);
-        
-        let msg_transfer = WasmMsg::Execute { 
-            contract_addr: tomb.to_string(), 
-            msg: to_binary(
-                &BasisAssetMsg::Transfer { 
-                    recipient: DAOFUND.load(deps.storage)?.to_string(), 
-                    amount: daofund_shared_amount
-                }
-            )?, 
-            funds: vec![]
-        };
-        msgs.push(CosmosMsg::Wasm(msg_transfer));
-    }
-
-    let mut devfund_shared_amount = Uint128::zero();
-    let devfund_shared_percent = DEVFUND_SHARED_PERCENT.load(deps.storage)?;
-    if devfund_shared_percent > Uint128::zero() {
-        devfund_shared_amount = amount * devfund_shared_percent / Uint128::from(10_000u128);
-        
+    let mut _amount = amount;
+    for (recipient, share) in get_seigniorage_shares(storage, amount)? {
         let msg_transfer = WasmMsg::Execute { 
             contract_addr: tomb.to_string(), 
             msg: to_binary(
                 &BasisAssetMsg::Transfer { 
-                    recipient: DEVFUND.load(deps.storage)?.to_string(), 
-                    amount: devfund_shared_amount
+                    recipient: recipient.address.to_string(), 
+                    amount: share
                 }
             )?, 
             funds: vec![]
         };
         msgs.push(CosmosMsg::Wasm(msg_transfer));
+        SEIGNIORAGE_PAID.update(storage, &recipient.name, |paid| -> StdResult<_> {
+            Ok(paid.unwrap_or_default() + share)
+        })?;
+        _amount -= share;
     }
 
-    let _amount = amount - daofund_shared_amount - devfund_shared_amount;
-
     let msg_approve_0 = WasmMsg::Execute { 
         contract_addr: tomb.to_string(), 
         msg: to_binary(
             &BasisAssetMsg::Approve { 
-                spender: MASONRY.load(deps.storage)?.to_string(), 
+                spender: MASONRY.load(storage)?.to_string(), 
                 amount: Uint128::zero()
             }
         )?, 
@@ -840,7 +1466,7 @@ pub fn try_send_to_masonry(
         contract_addr: tomb.to_string(), 
         msg: to_binary(
             &BasisAssetMsg::Approve { 
-                spender: MASONRY.load(deps.storage)?.to_string(), 
+                spender: MASONRY.load(storage)?.to_string(), 
                 amount: _amount
             }
         )?, 
@@ -849,7 +1475,7 @@ pub fn try_send_to_masonry(
     msgs.push(CosmosMsg::Wasm(msg_approve_1));
 
     let msg_allocate = WasmMsg::Execute { 
-        contract_addr: MASONRY.load(deps.storage)?.to_string(), 
+        contract_addr: MASONRY.load(storage)?.to_string(), 
         msg: to_binary(
             &MasonryMsg::AllocateSeigniorage {  
                 amount: _amount
@@ -873,36 +1499,40 @@ pub fn try_send_to_bond_treasury(
 )
     -> Result<Response, ContractError>
 {
-    let bond_treasury = BOND_TREASURY.load(deps.storage)?;
-    let treasury_balance = query_token_balance(
-        &deps.querier, TOMB.load(deps.storage)?, bond_treasury.clone())?;
-
-    let treasury_vested: Uint128 = deps.querier.query_wasm_smart(
-        BOND_TREASURY.load(deps.storage)?, 
-        &BondTreasuryQuery::TotalVested {  }
-    )?;
-    
-    if treasury_vested >= treasury_balance {
+    check_onlyself(&env, info.sender)?;
+    let mint_amount = get_bond_treasury_mint_amount(deps.storage, &deps.querier, amount)?;
+    Ok(send_to_bond_treasury(deps.storage, mint_amount)?)
+}
+
+fn send_to_bond_treasury(
+    storage: &dyn Storage,
+    mint_amount: Uint128
+)
+    -> StdResult<Response>
+{
+    if mint_amount.is_zero() {
         return Ok(Response::new());
-    } else{
-        let unspent = treasury_balance - treasury_vested;
-        if amount > unspent {
-            let msg = WasmMsg::Execute { 
-                contract_addr: TOMB.load(deps.storage)?.to_string(), 
-                msg: to_binary(
-                    &BasisAssetMsg::Mint { 
-                        recipient: bond_treasury.to_string(), 
-                        amount: amount - unspent 
-                    })?, 
-                funds: vec![]
-            };
-            return Ok(Response::new()
-                .add_attribute("action", "send to bond treasury")
-                .add_message(msg)
-            );
-        }
-        Ok(Response::new())
     }
+    let bond_treasury = BOND_TREASURY.load(storage)?;
+    let msg = WasmMsg::Execute { 
+        contract_addr: TOMB.load(storage)?.to_string(), 
+        msg: to_binary(
+            &BasisAssetMsg::Mint { 
+                recipient: bond_treasury.to_string(), 
+                amount: mint_amount
+            })?, 
+        funds: vec![]
+    };
+    // puts the minted TOMB up for sale
+    let msg_top_up = WasmMsg::Execute {
+        contract_addr: bond_treasury.to_string(),
+        msg: to_binary(&BondTreasuryMsg::TopUp { amount: mint_amount })?,
+        funds: vec![]
+    };
+    Ok(Response::new()
+        .add_attribute("action", "send to bond treasury")
+        .add_messages([msg, msg_top_up])
+    )
 }
 
 pub fn calculate_max_supply_expansion_percent(
@@ -911,17 +1541,9 @@ pub fn calculate_max_supply_expansion_percent(
 )
     ->StdResult<Uint128>
 {
-    let supply_tiers = SUPPLY_TIERS.load(storage)?;
-    let max_expansion_tiers = MAX_EXPANSION_TIERS.load(storage)?;
-
-    for tier_id in (0..=8).rev() {
-        if tomb_supply >= supply_tiers[tier_id] {
-            MAX_SUPPLY_EXPANSION_PERCENT.save(storage, 
-                &max_expansion_tiers[tier_id])?;
-            break;
-        }
-    }
-    Ok(MAX_SUPPLY_EXPANSION_PERCENT.load(storage)?)
+    let max_supply_expansion_percent = get_max_supply_expansion_percent(storage, tomb_supply)?;
+    MAX_SUPPLY_EXPANSION_PERCENT.save(storage, &max_supply_expansion_percent)?;
+    Ok(max_supply_expansion_percent)
 }
 
 pub fn try_allocate_seigniorage(
@@ -931,84 +1553,87 @@ pub fn try_allocate_seigniorage(
  )
     ->Result<Response, ContractError>
 {
+    PAUSE.assert_not_paused(deps.storage, &Operation::AllocateSeigniorage)?;
     check_condition(deps.storage, env.clone())?;
-    check_epoch(deps.storage, env.clone())?;
+    let epoch_res = check_epoch(deps.storage, &deps.querier, env.clone())?;
     check_operator(deps.storage, deps.querier, env.clone())?;
- 
-    let mut deps = deps;
-    execute(deps.branch(), env.clone(), info.clone(), 
-        ExecuteMsg::UpdateTombPrice{})?;
-
-    let previous_epoch_tomb_price = get_tomb_price(deps.storage, &deps.querier)?;
-    PREVIOUS_EPOCH_TOMB_PRICE.save(deps.storage, &previous_epoch_tomb_price)?;
-
-    let mut seigniorage_saved = SEIGNIORAGE_SAVED.load(deps.storage)?;
-    let tomb_supply = get_tomb_circulating_supply(deps.storage, &deps.querier)? 
-                                    - seigniorage_saved;
-
-    let bond_supply_expansion_percent = BOND_SUPPLY_EXPANSION_PERCENT.load(deps.storage)?;
-    
-    execute(deps.branch(), env.clone(), info.clone(),
-        ExecuteMsg::SendToBondTreasury {
-            amount: tomb_supply * bond_supply_expansion_percent / Uint128::from(10_000u128)
-        })?;
 
-    if EPOCH.load(deps.storage)? < BOOTSTRAP_EPOCHS.load(deps.storage)? {
-        // 28 first epochs with 4.5% expansion
-        let bootstrap_supply_expansion_percent = BOOTSTRAP_SUPPLY_EXPANSION_PERCENT.load(deps.storage)?;
-        execute(deps.branch(), env.clone(), info.clone(),
-            ExecuteMsg::SendToMasonry { 
-                amount: tomb_supply * bootstrap_supply_expansion_percent / Uint128::from(10_000u128)
-            })?;
-    } else {
-        if previous_epoch_tomb_price > TOMB_PRICE_CEILING.load(deps.storage)? {
-            // Expansion ($TOMB Price > 1 $FTM): there is some seigniorage to be allocated
-            let bond_supply = get_total_supply(&deps.querier, TBOND.load(deps.storage)?)?;
-            let mut percentage = previous_epoch_tomb_price - TOMB_PRICE_ONE.load(deps.storage)?;
-            let mut saved_for_bond = Uint128::zero();
-            let saved_for_masonry: Uint128;
-            let mse = calculate_max_supply_expansion_percent(deps.storage, tomb_supply)? * Uint128::from((10u64).pow(14u32));
-
-            if percentage > mse {
-                percentage = mse;
-            }
-            if seigniorage_saved >= bond_supply * BOND_DEPLETION_FLOOR_PERCENT.load(deps.storage)? / Uint128::from(10_000u128) {
-                // saved enough to pay debt, mint as usual rate
-                saved_for_masonry = tomb_supply * percentage / Uint128::from(ETHER);
-            } else {
-                // have not saved enough to pay debt, mint more
-                let seigniorage = tomb_supply * percentage / Uint128::from(ETHER);
-                saved_for_masonry = seigniorage * SEIGNIORAGE_EXPANSION_FLOOR_PERCENT.load(deps.storage)? / Uint128::from(10_000u128);
-                saved_for_bond = seigniorage - saved_for_masonry;
-                if MINTING_FACTOR_FOR_PAYING_DEBT.load(deps.storage)?> Uint128::zero() {
-                    saved_for_bond = saved_for_bond * MINTING_FACTOR_FOR_PAYING_DEBT.load(deps.storage)? / Uint128::from(10_000u128);
-                }
+    let mut res = Response::new()
+        .add_attribute("action", "allocate seignorage")
+        .add_attributes(epoch_res.attributes)
+        .add_events(epoch_res.events)
+        .add_submessage(update_tomb_price(deps.storage)?);
+
+    let record = get_epoch_allocation(deps.storage, &deps.querier,
+        EPOCH.load(deps.storage)?, EPOCH_SUPPLY_CONTRACTION_LEFT.load(deps.storage)?)?;
+    if record.circuit_breaker_tripped {
+        let breaker = CIRCUIT_BREAKER.load(deps.storage)?;
+        let trips = CIRCUIT_BREAKER_TRIPS.may_load(deps.storage)?.unwrap_or_default() + Uint128::from(1u128);
+        let mut event = Event::new("circuit_breaker")
+            .add_attribute("previous_price", PREVIOUS_EPOCH_TOMB_PRICE.load(deps.storage)?.to_string())
+            .add_attribute("price", record.tomb_price.to_string());
+        if trips >= breaker.reanchor_after {
+            // the move lasted, its price becomes the reference and the bonds trade again
+            PREVIOUS_EPOCH_TOMB_PRICE.save(deps.storage, &record.tomb_price)?;
+            CIRCUIT_BREAKER_TRIPS.remove(deps.storage);
+            if breaker.pause_bonds {
+                PAUSE.remove_paused(deps.storage, &[Operation::BuyBonds, Operation::RedeemBonds])?;
             }
-            if saved_for_masonry > Uint128::zero() {
-                execute(deps.branch(), env.clone(), info.clone(),
-                    ExecuteMsg::SendToMasonry { amount: saved_for_masonry })?;
-            }
-            if saved_for_bond > Uint128::zero() {
-                seigniorage_saved += saved_for_bond;
-                SEIGNIORAGE_SAVED.save(deps.storage, &seigniorage_saved)?;
-
-                let msg  = WasmMsg::Execute { 
-                    contract_addr: TOMB.load(deps.storage)?.to_string(), 
-                    msg: to_binary(
-                        &BasisAssetMsg::Mint { 
-                            recipient: env.contract.address.to_string(), 
-                            amount: saved_for_bond }
-                    )?, 
-                    funds: vec![]
-                };
-                return Ok(Response::new()
-                .add_attribute("action", "allocate seignorage")
-                .add_message(msg));
+            event = event.add_attribute("reanchored", trips.to_string());
+        } else {
+            // a tripped price is not trusted, the next epoch is compared with the last trusted one
+            CIRCUIT_BREAKER_TRIPS.save(deps.storage, &trips)?;
+            if breaker.pause_bonds {
+                PAUSE.add_paused(deps.storage, vec![Operation::BuyBonds, Operation::RedeemBonds])?;
+                event = event.add_attribute("paused", "bonds");
             }
         }
+        res = res.add_event(event);
+    } else {
+        PREVIOUS_EPOCH_TOMB_PRICE.save(deps.storage, &record.tomb_price)?;
+        CIRCUIT_BREAKER_TRIPS.remove(deps.storage);
     }
-    Ok(Response::new()
-        .add_attribute("action", "allocate seignorage"))
+    if !record.bootstrap && record.tomb_price > TOMB_PRICE_CEILING.load(deps.storage)? {
+        calculate_max_supply_expansion_percent(deps.storage, record.tomb_supply)?;
+    }
+
+    res = compose(res, send_to_bond_treasury(deps.storage, record.to_bond_treasury)?);
+
+    let saved_for_masonry = record.to_recipients.iter().fold(record.to_masonry, |total, share| total + share.amount);
+    if saved_for_masonry > Uint128::zero() {
+        res = compose(res, send_to_masonry(deps.storage, &env, saved_for_masonry)?);
+    }
+    if record.to_reserve > Uint128::zero() {
+        let seigniorage_saved = SEIGNIORAGE_SAVED.load(deps.storage)? + record.to_reserve;
+        SEIGNIORAGE_SAVED.save(deps.storage, &seigniorage_saved)?;
+
+        let msg  = WasmMsg::Execute { 
+            contract_addr: TOMB.load(deps.storage)?.to_string(), 
+            msg: to_binary(
+                &BasisAssetMsg::Mint { 
+                    recipient: env.contract.address.to_string(), 
+                    amount: record.to_reserve }
+            )?, 
+            funds: vec![]
+        };
+        res = res.add_message(msg);
+    }
+    if record.to_keeper > Uint128::zero() {
+        let msg = WasmMsg::Execute { 
+            contract_addr: TOMB.load(deps.storage)?.to_string(), 
+            msg: to_binary(
+                &BasisAssetMsg::Mint { 
+                    recipient: info.sender.to_string(), 
+                    amount: record.to_keeper }
+            )?, 
+            funds: vec![]
+        };
+        res = res.add_message(msg)
+            .add_attribute("keeper_bounty", record.to_keeper.to_string());
+    }
+    EPOCH_HISTORY.save(deps.storage, U64Key::from(record.epoch), &record)?;
+
+    Ok(res.add_event(seigniorage(&record)?))
 }
 
 pub fn try_governance_recover_unsupported(
@@ -1039,12 +1664,13 @@ pub fn try_governance_recover_unsupported(
         funds: vec![]
     };
     Ok(Response::new()
-        .add_attribute("action", "goverance recover unsupported")
+        .add_attribute("action", "governance recover unsupported")
+        .add_event(token_recovery("treasury", &token, amount, &to))
         .add_message(msg)
     )
 }
 
-pub fn try_masonry_set_operator(
+pub fn try_masonry_propose_operator(
     deps: DepsMut,
     info: MessageInfo,
     operator: Addr
@@ -1056,12 +1682,33 @@ pub fn try_masonry_set_operator(
     let msg = WasmMsg::Execute { 
         contract_addr: MASONRY.load(deps.storage)?.to_string(), 
         msg: to_binary(
-            &MasonryMsg::SetOperator { operator: operator }
+            &MasonryMsg::ProposeOperator { operator }
         )?, 
         funds: vec![]
     };
     Ok(Response::new()
-        .add_attribute("action", "Masonry set operator")
+        .add_attribute("action", "Masonry propose operator")
+        .add_message(msg)
+    )
+}
+
+pub fn try_masonry_accept_operator(
+    deps: DepsMut,
+    info: MessageInfo,
+)
+    ->Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+
+    let msg = WasmMsg::Execute { 
+        contract_addr: MASONRY.load(deps.storage)?.to_string(), 
+        msg: to_binary(
+            &MasonryMsg::AcceptOperator {  }
+        )?, 
+        funds: vec![]
+    };
+    Ok(Response::new()
+        .add_attribute("action", "Masonry accept operator")
         .add_message(msg)
     )
 }
@@ -1084,7 +1731,31 @@ pub fn try_masonry_set_lockup(
         funds: vec![]
     };
     Ok(Response::new()
-        .add_attribute("action", "Masonry set operator")
+        .add_attribute("action", "Masonry set lockup")
+        .add_attribute("withdraw_lockup_epochs", withdraw_lockup_epochs.to_string())
+        .add_attribute("reward_lockup_epochs", reward_lockup_epochs.to_string())
+        .add_message(msg)
+    )
+}
+
+pub fn try_masonry_set_guardian(
+    deps: DepsMut,
+    info: MessageInfo,
+    guardian: Option<Addr>
+)
+    ->Result<Response, ContractError>
+{
+    check_onlyoperator(deps.storage, info.sender)?;
+
+    let msg = WasmMsg::Execute { 
+        contract_addr: MASONRY.load(deps.storage)?.to_string(), 
+        msg: to_binary(
+            &MasonryMsg::SetGuardian { guardian }
+        )?, 
+        funds: vec![]
+    };
+    Ok(Response::new()
+        .add_attribute("action", "Masonry set guardian")
         .add_message(msg)
     )
 }
@@ -1123,6 +1794,7 @@ pub fn try_masonry_governance_recover_unsupported(
 {
     check_onlyoperator(deps.storage, info.sender)?;
 
+    let event = token_recovery("masonry", &token, amount, &to);
     let msg = WasmMsg::Execute { 
         contract_addr: MASONRY.load(deps.storage)?.to_string(), 
         msg: to_binary(
@@ -1132,6 +1804,7 @@ pub fn try_masonry_governance_recover_unsupported(
     };
     Ok(Response::new()
         .add_attribute("action", "Masonry governance recover unsupported")
+        .add_event(event)
         .add_message(msg)
     )
 }
@@ -0,0 +1,82 @@
+use cosmwasm_std::{to_vec, Addr, Event, StdError, StdResult, Uint128};
+use serde::Serialize;
+
+use crate::msg::{EpochRecord, QueuedTransaction};
+
+// Every response keeps its `action` attribute, state changes add custom events
+// (`wasm-<type>` once emitted):
+//
+// parameter_change      one per parameter touched by a setter
+//     parameter         name of the parameter, e.g. max_debt_ratio_percent
+//     old, new          value before and after, `none` while unset
+// bonds_bought
+//     sender, tomb_amount, bond_amount, tomb_price, discount_rate
+// bonds_redeemed
+//     sender, bond_amount, tomb_amount, tomb_price, premium_rate
+// seigniorage           one per allocation, the fields of its EpochRecord
+// skip_epochs           epochs skipped by CatchUpPolicy::Skip
+//     from, to
+// circuit_breaker       the allocation tripped the circuit breaker
+//     previous_price, price, paused (`bonds` when it paused them),
+//     reanchored        tripped allocations in a row before price became the reference
+// timelock_transaction  a queued setter changed status
+//     status            queued, executed, cancelled or expired
+//     id, eta, msg      msg is the json of the queued ExecuteMsg
+// token_recovery        unsupported tokens sent out by governance
+//     holder            treasury or masonry
+//     token, amount, to
+//
+// Amounts and prices are 18 decimals integers, percents are basis points and
+// structured values (tiers, policies, address lists) are json.
+
+pub fn parameter_change(parameter: &str, old: impl ToString, new: impl ToString) -> Event {
+    Event::new("parameter_change")
+        .add_attribute("parameter", parameter)
+        .add_attribute("old", old.to_string())
+        .add_attribute("new", new.to_string())
+}
+
+// attribute values cannot be empty
+pub fn optional_value<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_else(|| "none".to_string())
+}
+
+// json of a structured value
+pub fn json_value<T: Serialize>(value: Option<&T>) -> StdResult<String> {
+    match value {
+        Some(value) => String::from_utf8(to_vec(value)?).map_err(StdError::invalid_utf8),
+        None => Ok(optional_value::<String>(None))
+    }
+}
+
+pub fn seigniorage(record: &EpochRecord) -> StdResult<Event> {
+    Ok(Event::new("seigniorage")
+        .add_attribute("epoch", record.epoch.to_string())
+        .add_attribute("tomb_price", record.tomb_price.to_string())
+        .add_attribute("tomb_supply", record.tomb_supply.to_string())
+        .add_attribute("bootstrap", record.bootstrap.to_string())
+        .add_attribute("expansion_percent", record.expansion_percent.to_string())
+        .add_attribute("to_masonry", record.to_masonry.to_string())
+        .add_attribute("to_recipients", json_value(Some(&record.to_recipients))?)
+        .add_attribute("to_bond_treasury", record.to_bond_treasury.to_string())
+        .add_attribute("to_reserve", record.to_reserve.to_string())
+        .add_attribute("to_keeper", record.to_keeper.to_string())
+        .add_attribute("circuit_breaker_tripped", record.circuit_breaker_tripped.to_string())
+        .add_attribute("epoch_supply_contraction_left", record.epoch_supply_contraction_left.to_string()))
+}
+
+pub fn timelock_transaction(status: &str, transaction: &QueuedTransaction) -> StdResult<Event> {
+    Ok(Event::new("timelock_transaction")
+        .add_attribute("status", status)
+        .add_attribute("id", transaction.id.to_string())
+        .add_attribute("eta", transaction.eta.to_string())
+        .add_attribute("msg", json_value(Some(&transaction.msg))?))
+}
+
+pub fn token_recovery(holder: &str, token: &Addr, amount: Uint128, to: &Addr) -> Event {
+    Event::new("token_recovery")
+        .add_attribute("holder", holder)
+        .add_attribute("token", token.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("to", to.to_string())
+}
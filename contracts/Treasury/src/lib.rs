@@ -1,4 +1,5 @@
 pub mod contract;
+pub mod events;
 pub mod query;
 mod error;
 pub mod msg;
@@ -7,7 +8,4 @@ pub mod util;
 pub use crate::error::ContractError;
 
 #[cfg(test)]
-mod test;
-
-#[cfg(test)]
-mod mock_querier;
\ No newline at end of file
+mod test;
\ No newline at end of file
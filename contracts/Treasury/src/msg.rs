@@ -1,4 +1,5 @@
 use cosmwasm_std::{Uint128, Addr};
+use cw20::Cw20ReceiveMsg;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +8,9 @@ pub struct InstantiateMsg {
 
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
@@ -18,14 +22,23 @@ pub enum ExecuteMsg {
         masonry: Addr,
         genesis_pool: Addr,
         bond_treasury: Addr,
-        start_time: Uint128
+        start_time: Uint128,
+        // epoch length in seconds, between 1 and 48 hours
+        period: Uint128
     },
-    SetOperator{
+    ProposeOperator{
         operator: Addr,
     },
+    AcceptOperator{},
+    CancelProposal{},
+    RenounceOperator{},
     SetMasonry{
         masonry: Addr
     },
+    // the epoch in progress keeps its length, the new one applies from its end
+    SetPeriod{
+        period: Uint128
+    },
     SetBondTreasury{
         bond_treasury: Addr
     },
@@ -42,6 +55,14 @@ pub enum ExecuteMsg {
         index: Uint128,
         value: Uint128
     },
+    SetMaxExpansionTiersEntry{
+        index: Uint128,
+        value: Uint128
+    },
+    // replaces the whole table, supplies strictly ascending
+    SetSupplyTiers{
+        tiers: Vec<SupplyTier>
+    },
     SetBondDepletionFloorPercent{
         bond_depletion_floor_percent: Uint128
     },
@@ -55,11 +76,9 @@ pub enum ExecuteMsg {
         bootstrap_epochs: Uint128, 
         bootstrap_supply_expansion_percent: Uint128
     },
-    SetExtraFunds{
-        dao_fund: Addr,
-        dao_fund_shared_percent: Uint128,
-        dev_fund: Addr,
-        dev_fund_shared_percent: Uint128
+    // replaces the whole list, names unique, shares summing up to MAX_SEIGNIORAGE_RECIPIENTS_SHARE
+    SetSeigniorageRecipients{
+        recipients: Vec<SeigniorageRecipient>
     },
     SetMaxDiscountRate{
         max_discount_rate: Uint128
@@ -82,15 +101,67 @@ pub enum ExecuteMsg {
     SetBondSupplyExpansionPercent{
         bond_supply_expansion_percent: Uint128
     },
+    AddExcludedAddress{
+        address: Addr
+    },
+    RemoveExcludedAddress{
+        address: Addr
+    },
+    // None stops paying keepers
+    SetKeeperBounty{
+        bounty: Option<KeeperBounty>
+    },
+    // None allocates missed epochs back to back without limit
+    SetCatchUpPolicy{
+        policy: Option<CatchUpPolicy>
+    },
+    SetBondPricing{
+        pricing: BondPricing
+    },
+    // None trusts every TWAP
+    SetCircuitBreaker{
+        breaker: Option<CircuitBreaker>
+    },
+    // seconds between queueing and executing a setter, None applies setters immediately
+    SetTimelockDelay{
+        delay: Option<Uint128>
+    },
+    // setters only, executable once the timelock delay has passed
+    QueueTransaction{
+        msg: Box<ExecuteMsg>
+    },
+    // drops the transaction without executing it once the grace period has passed
+    ExecuteTransaction{
+        id: u64
+    },
+    CancelTransaction{
+        id: u64
+    },
+    // None leaves nobody able to pause
+    SetGuardian{
+        guardian: Option<Addr>
+    },
+    // guardian only
+    Pause{
+        operations: Vec<Operation>
+    },
+    Unpause{
+        operations: Vec<Operation>
+    },
     UpdateTombPrice{ },
+    // returns the minted bond amount as data
     BuyBonds{
         tomb_amount: Uint128,
+        // highest TOMB price accepted
         target_price: Uint128,
     },
     RedeemBonds{
         bond_amount: Uint128,
+        // lowest TOMB price accepted
         target_price: Uint128,
     },
+    // TOMB or TBOND sent with a Cw20HookMsg, no allowance needed
+    Receive(Cw20ReceiveMsg),
     SendToMasonry{
         amount: Uint128
     },
@@ -103,13 +174,19 @@ pub enum ExecuteMsg {
         amount: Uint128,
         to: Addr
     },
-    MasonrySetOperator{
+    // the proposed operator takes over the Masonry once it accepts there
+    MasonryProposeOperator{
         operator: Addr
     },
+    // takes over a Masonry proposed to the Treasury
+    MasonryAcceptOperator{},
     MasonrySetLockup{
         withdraw_lockup_epochs: Uint128,
         reward_lockup_epochs: Uint128,
     },
+    MasonrySetGuardian{
+        guardian: Option<Addr>
+    },
     MasonryAllocationSeigniorage{
         amount: Uint128
     },
@@ -120,6 +197,20 @@ pub enum ExecuteMsg {
     },
 }
 
+// Bond trades paid with the tokens sent, on behalf of the Cw20 sender
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Cw20HookMsg {
+    // sent with TOMB
+    BuyBonds{
+        target_price: Uint128,
+    },
+    // sent with TBOND
+    RedeemBonds{
+        target_price: Uint128,
+    },
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
@@ -132,5 +223,301 @@ pub enum QueryMsg {
     GetRedeemableBonds{},
     GetBondDiscountRate{},
     GetBondPremiumRate{},
-    Epoch{}
+    Epoch{},
+    Config{},
+    State{},
+    // everything a bond buyer or redeemer looks at, in one response
+    BondMarket{},
+    // BondQuote of a BuyBonds or RedeemBonds sent now, whatever the target price
+    QuoteBuyBonds{
+        tomb_amount: Uint128
+    },
+    QuoteRedeemBonds{
+        bond_amount: Uint128
+    },
+    // sorted by address
+    ExcludedFromTotalSupply{
+        start_after: Option<Addr>,
+        limit: Option<u32>
+    },
+    EpochHistory{
+        start_after: Option<u64>,
+        limit: Option<u32>
+    },
+    // EpochRecord the next AllocateSeigniorage would write at the current TWAP
+    SimulateAllocation{},
+    SupplyTiers{},
+    KeeperBounty{},
+    CatchUpPolicy{},
+    BondPricing{},
+    CircuitBreaker{},
+    // with the amounts paid so far
+    SeigniorageRecipients{},
+    PendingOperator{},
+    TimelockDelay{},
+    // sorted by id
+    QueuedTransactions{
+        start_after: Option<u64>,
+        limit: Option<u32>
+    },
+    PauseStatus{}
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigResponse {
+    // None once the operator has been renounced
+    pub operator: Option<Addr>,
+    // core components
+    pub tomb: Addr,
+    pub tbond: Addr,
+    pub tshare: Addr,
+    pub masonry: Addr,
+    pub bond_treasury: Addr,
+    pub tomb_oracle: Addr,
+    // epoch
+    pub start_time: Uint128,
+    pub period: Uint128,
+    // price
+    pub tomb_price_one: Uint128,
+    pub tomb_price_ceiling: Uint128,
+    // expansion and contraction, percents in basis points
+    pub supply_tiers: Vec<Uint128>,
+    pub max_expansion_tiers: Vec<Uint128>,
+    pub max_supply_expansion_percent: Uint128,
+    pub bond_depletion_floor_percent: Uint128,
+    pub seigniorage_expansion_floor_percent: Uint128,
+    pub max_supply_contraction_percent: Uint128,
+    pub max_debt_ratio_percent: Uint128,
+    pub bond_supply_expansion_percent: Uint128,
+    pub bootstrap_epochs: Uint128,
+    pub bootstrap_supply_expansion_percent: Uint128,
+    // bond discount and premium
+    pub max_discount_rate: Uint128,
+    pub max_premium_rate: Uint128,
+    pub discount_percent: Uint128,
+    pub premium_threshold: Uint128,
+    pub premium_percent: Uint128,
+    pub minting_factor_for_paying_debt: Uint128,
+    // paid before the Masonry on every expansion
+    pub seigniorage_recipients: Vec<SeigniorageRecipient>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StateResponse {
+    pub epoch: Uint128,
+    pub next_epoch_point: Uint128,
+    pub seigniorage_saved: Uint128,
+    pub epoch_supply_contraction_left: Uint128,
+    // zero until the first allocation, not updated by allocations that trip the circuit breaker
+    pub previous_epoch_tomb_price: Uint128,
+    pub tomb_circulating_supply: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BondMarketResponse {
+    // TWAP of the last oracle update, bonds trade at it
+    pub tomb_price: Uint128,
+    // price of selling one TOMB in the pair right now
+    pub spot_price: Uint128,
+    pub discount_rate: Uint128,
+    pub premium_rate: Uint128,
+    // TBOND supply over the circulating TOMB, in basis points
+    pub debt_ratio_percent: Uint128,
+    pub max_debt_ratio_percent: Uint128,
+    pub epoch_supply_contraction_left: Uint128,
+    pub burnable_tomb_left: Uint128,
+    pub redeemable_bonds: Uint128,
+    // TOMB held to pay redemptions
+    pub treasury_tomb_balance: Uint128,
+}
+
+// Expansion cap applied once the circulating supply reaches `supply`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SupplyTier {
+    pub supply: Uint128,
+    // in basis points
+    pub max_expansion_percent: Uint128,
+}
+
+// Side recipient of the seigniorage saved for the Masonry, e.g. a DAO fund or a buyback contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SeigniorageRecipient {
+    pub name: String,
+    pub address: Addr,
+    // in basis points
+    pub share: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SeigniorageRecipientResponse {
+    pub name: String,
+    pub address: Addr,
+    pub share: Uint128,
+    // cumulative TOMB sent to the name, kept across address changes
+    pub paid: Uint128,
+}
+
+// TOMB an allocation sent to a recipient
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SeigniorageShare {
+    pub name: String,
+    pub amount: Uint128,
+}
+
+// Guard against manipulated pair prices feeding the Oracle, it trips when the TWAP
+// moved more than `max_price_move` since the last epoch that did not trip it
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CircuitBreaker {
+    // in basis points of PREVIOUS_EPOCH_TOMB_PRICE
+    pub max_price_move: Uint128,
+    // expansion cap of a tripped allocation in basis points, zero refuses any expansion
+    pub tripped_expansion_percent: Uint128,
+    // also pauses BuyBonds and RedeemBonds until the guardian resumes them or it re-anchors
+    pub pause_bonds: bool,
+    // allocations in a row it may trip before their price is trusted as the new reference,
+    // so a lasting move stops tripping it
+    pub reanchor_after: Uint128,
+}
+
+// What the guardian can pause
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    BuyBonds,
+    RedeemBonds,
+    AllocateSeigniorage
+}
+
+// Outcome of a bond trade at the current TWAP
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BondQuote {
+    // TBOND minted by BuyBonds, TOMB paid by RedeemBonds
+    Amount{
+        amount: Uint128,
+        tomb_price: Uint128,
+        rate: Uint128
+    },
+    Unavailable{
+        reason: BondQuoteFailure
+    }
+}
+
+// Why a bond trade would be refused, in the order the checks run
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BondQuoteFailure {
+    Paused{},
+    NotStartedYet{},
+    ZeroAmount{},
+    // bonds are bought below the peg and redeemed above it
+    PriceNotEligible{
+        tomb_price: Uint128
+    },
+    InvalidRate{},
+    NotEnoughContractionLeft{
+        tomb_amount_left: Uint128
+    },
+    OverMaxDebtRatio{
+        bond_amount_left: Uint128
+    },
+    NotEnoughBudget{
+        tomb_balance: Uint128
+    }
+}
+
+// What AllocateSeigniorage does when epochs were missed
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CatchUpPolicy {
+    // jumps to the current epoch, the missed ones get no allocation
+    Skip{},
+    // allocates missed epochs one by one, at most `max_epochs` in a block
+    CapPerBlock{
+        max_epochs: u64
+    }
+}
+
+// How the bond discount and premium rates follow the TWAP
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BondPricing {
+    // DISCOUNT_PERCENT and PREMIUM_PERCENT of the distance to the peg, the default
+    Linear{},
+    // rates interpolated between points sorted by price, flat beyond the first and last ones
+    PiecewiseLinear{
+        // prices up to TOMB_PRICE_ONE
+        discount: Vec<PricePoint>,
+        // prices above TOMB_PRICE_ONE, used above TOMB_PRICE_CEILING
+        premium: Vec<PricePoint>
+    },
+    // linear rates, the discount shrinks to nothing as the debt ratio goes from
+    // `taper_from_percent` to MAX_DEBT_RATIO_PERCENT
+    DebtRatio{
+        taper_from_percent: Uint128
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PricePoint {
+    pub price: Uint128,
+    // TBOND per TOMB when buying, TOMB per TBOND when redeeming
+    pub rate: Uint128,
+}
+
+// TOMB minted to whoever opens an epoch with AllocateSeigniorage, only out of an expansion
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KeeperBounty {
+    // up to the TOMB minted by the allocation
+    Fixed{
+        amount: Uint128
+    },
+    // share of the TOMB minted by the allocation, in basis points, up to `cap`
+    Percent{
+        percent: Uint128,
+        cap: Uint128
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct KeeperBountyResponse {
+    pub bounty: Option<KeeperBounty>,
+    // paid by the next allocation at the current TWAP
+    pub next_bounty: Uint128,
+}
+
+// Outcome of the seigniorage allocation that opened an epoch
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EpochRecord {
+    pub epoch: u64,
+    // TWAP used for the allocation
+    pub tomb_price: Uint128,
+    // circulating supply without the saved seigniorage
+    pub tomb_supply: Uint128,
+    pub bootstrap: bool,
+    // in basis points
+    pub expansion_percent: Uint128,
+    pub to_masonry: Uint128,
+    // in the order of the recipient list, recipients paid nothing omitted
+    #[serde(default)]
+    pub to_recipients: Vec<SeigniorageShare>,
+    pub to_bond_treasury: Uint128,
+    // added to SEIGNIORAGE_SAVED to pay bonds
+    pub to_reserve: Uint128,
+    #[serde(default)]
+    pub to_keeper: Uint128,
+    // the TWAP move tripped the circuit breaker
+    #[serde(default)]
+    pub circuit_breaker_tripped: bool,
+    pub epoch_supply_contraction_left: Uint128,
+}
+
+// Setter waiting for the timelock delay
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QueuedTransaction {
+    pub id: u64,
+    pub msg: ExecuteMsg,
+    // earliest time it can be executed, it expires TIMELOCK_GRACE_PERIOD later
+    pub eta: Uint128,
 }
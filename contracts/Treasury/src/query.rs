@@ -10,7 +10,10 @@ use crate::state::{EPOCH};
 use crate::util::{is_initialized, next_epoch_point, get_tomb_price,
     get_tomb_updated_price, get_reserve, get_burnable_tomb_left,
     get_redeemable_bonds, get_bond_discount_rate,
-    get_bond_premium_rate};
+    get_bond_premium_rate, get_config, get_state, get_excluded_from_total_supply, get_epoch_history, get_queued_transactions,
+    get_allocation_simulation, get_supply_tiers, get_keeper_bounty, get_seigniorage_recipients, get_bond_pricing};
+use crate::util::{get_bond_market, quote_buy_bonds, quote_redeem_bonds};
+use crate::state::{CATCH_UP_POLICY, OWNERSHIP, TIMELOCK_DELAY, PAUSE, CIRCUIT_BREAKER};
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
@@ -53,6 +56,78 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 
         QueryMsg::Epoch{ } => {
             to_binary( & EPOCH.load(deps.storage)?)
+        },
+
+        QueryMsg::Config{ } => {
+            to_binary(&get_config(deps.storage)?)
+        },
+
+        QueryMsg::State{ } => {
+            to_binary(&get_state(deps.storage, &deps.querier)?)
+        },
+
+        QueryMsg::BondMarket{ } => {
+            to_binary(&get_bond_market(deps.storage, &deps.querier, env)?)
+        },
+
+        QueryMsg::QuoteBuyBonds{ tomb_amount } => {
+            to_binary(&quote_buy_bonds(deps.storage, &deps.querier, &env, tomb_amount)?)
+        },
+
+        QueryMsg::QuoteRedeemBonds{ bond_amount } => {
+            to_binary(&quote_redeem_bonds(deps.storage, &deps.querier, &env, bond_amount)?)
+        },
+
+        QueryMsg::ExcludedFromTotalSupply{ start_after, limit } => {
+            to_binary(&get_excluded_from_total_supply(deps.storage, start_after, limit)?)
+        },
+
+        QueryMsg::EpochHistory{ start_after, limit } => {
+            to_binary(&get_epoch_history(deps.storage, start_after, limit)?)
+        },
+
+        QueryMsg::SimulateAllocation{ } => {
+            to_binary(&get_allocation_simulation(deps.storage, &deps.querier, &env)?)
+        },
+
+        QueryMsg::SupplyTiers{ } => {
+            to_binary(&get_supply_tiers(deps.storage)?)
+        },
+
+        QueryMsg::KeeperBounty{ } => {
+            to_binary(&get_keeper_bounty(deps.storage, &deps.querier, &env)?)
+        },
+
+        QueryMsg::CatchUpPolicy{ } => {
+            to_binary(&CATCH_UP_POLICY.may_load(deps.storage)?)
+        }
+
+        QueryMsg::PendingOperator{ } => {
+            to_binary(&OWNERSHIP.pending_operator(deps.storage)?)
+        }
+
+        QueryMsg::TimelockDelay{ } => {
+            to_binary(&TIMELOCK_DELAY.may_load(deps.storage)?)
+        }
+
+        QueryMsg::QueuedTransactions{ start_after, limit } => {
+            to_binary(&get_queued_transactions(deps.storage, start_after, limit)?)
+        }
+
+        QueryMsg::BondPricing{ } => {
+            to_binary(&get_bond_pricing(deps.storage)?)
+        }
+
+        QueryMsg::CircuitBreaker{ } => {
+            to_binary(&CIRCUIT_BREAKER.may_load(deps.storage)?)
+        }
+
+        QueryMsg::SeigniorageRecipients{ } => {
+            to_binary(&get_seigniorage_recipients(deps.storage)?)
+        }
+
+        QueryMsg::PauseStatus{ } => {
+            to_binary(&PAUSE.status(deps.storage)?)
         }
     }
 }
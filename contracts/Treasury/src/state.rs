@@ -1,12 +1,20 @@
 use cosmwasm_std::{Addr, Uint128};
-use cw_storage_plus::{Item, Map, U128Key};
+use cw_storage_plus::{Item, Map, U128Key, U64Key};
+use lib::operator::Ownership;
+use lib::pause::Pause;
+use crate::msg::{EpochRecord, KeeperBounty, CatchUpPolicy, QueuedTransaction, Operation, CircuitBreaker, SeigniorageRecipient, BondPricing};
 
 pub const OPERATOR: Item<Addr> = Item::new("operator");
+pub const PENDING_OPERATOR: Item<Addr> = Item::new("pending operator");
+pub const OWNERSHIP: Ownership = Ownership::new(&OPERATOR, &PENDING_OPERATOR);
 pub const INITIALIZED: Item<bool> = Item::new("initialized");
 
     // epoch
 pub const START_TIME: Item<Uint128> = Item::new("starttime");
 pub const EPOCH: Item<Uint128> = Item::new("epoch");
+pub const PERIOD: Item<Uint128> = Item::new("period");
+// (epoch, end time of that epoch) from which the current period runs
+pub const PERIOD_ANCHOR: Item<(Uint128, Uint128)> = Item::new("period anchor");
 pub const EPOCH_SUPPLY_CONTRACTION_LEFT: Item<Uint128> = Item::new("epoch supply contract left");
 
     // exclusions from total supply
@@ -51,7 +59,40 @@ pub const PREMIUM_THRESHOLD: Item<Uint128> = Item::new("premium threshold");
 pub const PREMIUM_PERCENT: Item<Uint128> = Item::new("premium percent");
 pub const MINTING_FACTOR_FOR_PAYING_DEBT: Item<Uint128> = Item::new("minting factor for paying debt");
 
+pub const SEIGNIORAGE_RECIPIENTS: Item<Vec<SeigniorageRecipient>> = Item::new("seigniorage recipients");
+// cumulative TOMB paid, keyed by recipient name
+pub const SEIGNIORAGE_PAID: Map<&str, Uint128> = Map::new("seigniorage paid");
+// fixed extra funds of older deployments, moved to SEIGNIORAGE_RECIPIENTS by migrate
 pub const DAOFUND: Item<Addr> = Item::new("daofund");
 pub const DAOFUND_SHARED_PERCENT: Item<Uint128> = Item::new("daofund shared percent");
 pub const DEVFUND: Item<Addr> = Item::new("dev fund");
-pub const DEVFUND_SHARED_PERCENT: Item<Uint128> = Item::new("devfund shared percent");
\ No newline at end of file
+pub const DEVFUND_SHARED_PERCENT: Item<Uint128> = Item::new("devfund shared percent");
+
+// unset while keepers are not paid
+pub const KEEPER_BOUNTY: Item<KeeperBounty> = Item::new("keeper bounty");
+
+// unset while missed epochs are allocated back to back without limit
+pub const CATCH_UP_POLICY: Item<CatchUpPolicy> = Item::new("catch up policy");
+// height of the last allocation and the number of epochs it opened in that block
+pub const BLOCK_ALLOCATIONS: Item<(u64, u64)> = Item::new("block allocations");
+
+// BondPricing::Linear while unset
+pub const BOND_PRICING: Item<BondPricing> = Item::new("bond pricing");
+
+// unset while every TWAP is trusted
+pub const CIRCUIT_BREAKER: Item<CircuitBreaker> = Item::new("circuit breaker");
+// allocations in a row that tripped it, reset by a trusted price or a re-anchor
+pub const CIRCUIT_BREAKER_TRIPS: Item<Uint128> = Item::new("circuit breaker trips");
+
+    // seigniorage outcome of every epoch, keyed by the epoch it opened
+pub const EPOCH_HISTORY: Map<U64Key, EpochRecord> = Map::new("epoch history");
+
+    // timelock, unset while setters apply immediately
+pub const TIMELOCK_DELAY: Item<Uint128> = Item::new("timelock delay");
+pub const NEXT_TRANSACTION_ID: Item<u64> = Item::new("next transaction id");
+pub const QUEUED_TRANSACTIONS: Map<U64Key, QueuedTransaction> = Map::new("queued transactions");
+
+    // emergency pause
+pub const GUARDIAN: Item<Addr> = Item::new("guardian");
+pub const PAUSED: Item<Vec<Operation>> = Item::new("paused");
+pub const PAUSE: Pause<Operation> = Pause::new(&GUARDIAN, &PAUSED);
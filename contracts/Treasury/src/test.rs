@@ -6,7 +6,7 @@ use crate::contract::{execute, instantiate};
 use crate::query::{query};
 use crate::msg::{QueryMsg, ExecuteMsg, InstantiateMsg};
 
-use crate::mock_querier::mock_dependencies;
+use lib::mock_querier::mock_dependencies;
 
 #[test]
 fn workflow(){
@@ -1,9 +1,9 @@
 use crate::error::ContractError;
 
-use cosmwasm_std::{ Storage, Uint128, Addr, StdResult, StdError, Response, Env, QuerierWrapper, Querier};
+use cosmwasm_std::{ Storage, Uint128, Addr, StdResult, Response, Env, QuerierWrapper, Order, Event};
 use terraswap::querier;
 use crate::state::{
-    OPERATOR, INITIALIZED, START_TIME, EPOCH, EPOCH_SUPPLY_CONTRACTION_LEFT,
+    OPERATOR, INITIALIZED, START_TIME, EPOCH, PERIOD, PERIOD_ANCHOR, EPOCH_SUPPLY_CONTRACTION_LEFT,
     EXCLUDED_FROM_TOTALSUPPLY, TOMB, TBOND, TSHARE, MASONRY,
     BOND_TREASURY, TOMB_ORACLE, TOMB_PRICE_ONE, TOMB_PRICE_CEILING,
     SEIGNIORAGE_SAVED, SUPPLY_TIERS, MAX_EXPANSION_TIERS,
@@ -14,21 +14,37 @@ use crate::state::{
     PREVIOUS_EPOCH_TOMB_PRICE, MAX_DISCOUNT_RATE,
     MAX_PREMIUM_RATE, DISCOUNT_PERCENT, PREMIUM_PERCENT,
     PREMIUM_THRESHOLD, MINTING_FACTOR_FOR_PAYING_DEBT,
-    DAOFUND, DAOFUND_SHARED_PERCENT, DEVFUND,
-    DEVFUND_SHARED_PERCENT
+    SEIGNIORAGE_RECIPIENTS, SEIGNIORAGE_PAID, EPOCH_HISTORY, KEEPER_BOUNTY,
+    CATCH_UP_POLICY, BLOCK_ALLOCATIONS, QUEUED_TRANSACTIONS, CIRCUIT_BREAKER, BOND_PRICING
 };
-use crate::contract::{PERIOD};
+use crate::msg::{ConfigResponse, StateResponse, EpochRecord, SupplyTier, KeeperBounty, KeeperBountyResponse, CatchUpPolicy,
+    ExecuteMsg, QueuedTransaction, SeigniorageRecipient, SeigniorageRecipientResponse, SeigniorageShare,
+    BondPricing, PricePoint};
+use crate::msg::{BondMarketResponse, BondQuote, BondQuoteFailure, Operation};
+use crate::state::PAUSE;
 use BasisAsset::msg::{QueryMsg as BasisAssetQuery};
 use IMasonry::msg::{QueryMsg as MasonryQuery};
 use Oracle::msg::{QueryMsg as OracleQuery};
-use terraswap::querier::{query_token_balance};
-use terraswap::asset::{AssetInfo};
+use BondTreasury::msg::{QueryMsg as BondTreasuryQuery};
+use cw_storage_plus::Bound;
+use terraswap::querier::{query_token_balance, simulate};
+use terraswap::asset::{Asset, AssetInfo};
 
 pub const ETHER: u128 = 1_000_000_000_000_000_000u128;
 
+// pagination
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 pub fn check_onlyoperator(storage: &dyn Storage, sender: Addr) -> Result<Response, ContractError> {
-    let operator = OPERATOR.load(storage)?;
-    if operator != sender {
+    let operator = OPERATOR.may_load(storage)?;
+    if operator != Some(sender) {
+        return Err(ContractError::Unauthorized{});
+    }
+    Ok(Response::new())
+}
+pub fn check_onlyself(env: &Env, sender: Addr) -> Result<Response, ContractError> {
+    if sender != env.contract.address {
         return Err(ContractError::Unauthorized{});
     }
     Ok(Response::new())
@@ -41,19 +57,82 @@ pub fn check_condition(storage: &dyn Storage, env: Env) -> Result<Response, Cont
     }
     Ok(Response::new())
 }
-pub fn check_epoch(storage: &mut dyn Storage, env: Env) -> Result<Response, ContractError>{
+pub fn check_epoch(storage: &mut dyn Storage, querier: &QuerierWrapper, env: Env) -> Result<Response, ContractError>{
 
     if Uint128::from(env.block.time.seconds()) < next_epoch_point(storage)? {
         return Err(ContractError::NotOpenedYet{ });
     }
-    let mut epoch = EPOCH.load(storage)?;
-    epoch += Uint128::from(1u128);
-    EPOCH.save(storage, &epoch);
-    
-    // epochSupplyContractionLeft = (getTombPrice() > tombPriceCeiling) ? 0 : getTombCirculatingSupply().mul(maxSupplyContractionPercent).div(10000);
+    let mut res = Response::new();
+    let mut epoch = EPOCH.load(storage)? + Uint128::from(1u128);
+    match CATCH_UP_POLICY.may_load(storage)? {
+        Some(CatchUpPolicy::Skip {}) => {
+            let current_epoch = get_current_epoch(storage, &env)?;
+            if current_epoch > epoch {
+                res = res.add_event(Event::new("skip_epochs")
+                    .add_attribute("from", epoch.to_string())
+                    .add_attribute("to", (current_epoch - Uint128::from(1u128)).to_string()));
+                epoch = current_epoch;
+            }
+        },
+        Some(CatchUpPolicy::CapPerBlock { max_epochs }) => {
+            let height = env.block.height;
+            let allocations = match BLOCK_ALLOCATIONS.may_load(storage)? {
+                Some((last_height, count)) if last_height == height => count + 1,
+                _ => 1
+            };
+            if allocations > max_epochs {
+                return Err(ContractError::TreasuryError {
+                    msg: "Treasury: too many epochs allocated in this block".to_string()
+                });
+            }
+            BLOCK_ALLOCATIONS.save(storage, &(height, allocations))?;
+        },
+        None => {}
+    }
+    EPOCH.save(storage, &epoch)?;
 
+    let epoch_supply_contraction_left = get_epoch_supply_contraction(storage, querier)?;
+    EPOCH_SUPPLY_CONTRACTION_LEFT.save(storage, &epoch_supply_contraction_left)?;
+
+    Ok(res
+        .add_attribute("epoch", epoch.to_string())
+        .add_attribute("epoch_supply_contraction_left", epoch_supply_contraction_left.to_string()))
+}
+
+// epoch the block time falls in, the first one opening at the start time
+pub fn get_current_epoch(storage: &dyn Storage, env: &Env) -> StdResult<Uint128> {
+    let (anchor_epoch, anchor_time) = PERIOD_ANCHOR.load(storage)?;
+    let elapsed = Uint128::from(env.block.time.seconds()).checked_sub(anchor_time)
+        .unwrap_or_default();
+    Ok(anchor_epoch + elapsed / PERIOD.load(storage)? + Uint128::from(1u128))
+}
+
+pub fn check_period(period: Uint128) -> Result<Response, ContractError> {
+    let hour = Uint128::from(3600u128);
+
+    if period < Uint128::from(1u128) * hour || period > Uint128::from(48u128) * hour {
+        return Err(ContractError::ValueOutOfRange{ });
+    }
     Ok(Response::new())
 }
+
+// epoch the next allocation opens under the catch-up policy
+pub fn get_next_epoch(storage: &dyn Storage, env: &Env) -> StdResult<Uint128> {
+    let epoch = EPOCH.load(storage)? + Uint128::from(1u128);
+    if let Some(CatchUpPolicy::Skip {}) = CATCH_UP_POLICY.may_load(storage)? {
+        return Ok(std::cmp::max(epoch, get_current_epoch(storage, env)?));
+    }
+    Ok(epoch)
+}
+
+// no bonds for sale above the ceiling, otherwise up to MAX_SUPPLY_CONTRACTION_PERCENT of the circulating supply
+pub fn get_epoch_supply_contraction(storage: &dyn Storage, querier: &QuerierWrapper) -> StdResult<Uint128> {
+    if get_tomb_price(storage, querier)? > TOMB_PRICE_CEILING.load(storage)? {
+        return Ok(Uint128::zero());
+    }
+    Ok(get_tomb_circulating_supply(storage, querier)? * MAX_SUPPLY_CONTRACTION_PERCENT.load(storage)? / Uint128::from(10_000u128))
+}
+
 pub fn get_basisasset_operator(querier: QuerierWrapper, token: Addr) -> StdResult<Addr> {
     let operator: Addr = querier.query_wasm_smart(
         token, 
@@ -81,21 +160,22 @@ pub fn check_operator(storage: &dyn Storage, querier: QuerierWrapper,  env: Env)
     Ok(Response::new())
 }
 pub fn check_not_initialized(storage: &dyn Storage) -> Result<Response, ContractError> {
-    let initialized = INITIALIZED.load(storage)?;
+    let initialized = is_initialized(storage)?;
     if initialized {
         return Err(ContractError::AlreadyInitialized{})
     }
     Ok(Response::new())
 }
 
+// unset until Initialize
 pub fn is_initialized(storage: &dyn Storage) -> StdResult<bool>{
-    Ok(INITIALIZED.load(storage)?)
+    Ok(INITIALIZED.may_load(storage)?.unwrap_or(false))
 }
 // epoch
 pub fn next_epoch_point(storage: &dyn Storage) -> StdResult<Uint128> {
-    let starttime = START_TIME.load(storage)?;
+    let (anchor_epoch, anchor_time) = PERIOD_ANCHOR.load(storage)?;
     let epoch = EPOCH.load(storage)?;
-    Ok(starttime + epoch * Uint128::from(PERIOD))
+    Ok(anchor_time + (epoch - anchor_epoch) * PERIOD.load(storage)?)
 }
 
 // oracle
@@ -127,6 +207,15 @@ pub fn get_tomb_updated_price(storage: &dyn Storage, querier: &QuerierWrapper) -
 
     Ok(price)
 }
+// return of selling one TOMB in the Oracle's pair right now
+pub fn get_tomb_spot_price(storage: &dyn Storage, querier: &QuerierWrapper) -> StdResult<Uint128> {
+    let pair: Addr = querier.query_wasm_smart(TOMB_ORACLE.load(storage)?, &OracleQuery::Pair {  })?;
+    let offer_asset = Asset {
+        info: AssetInfo::Token { contract_addr: TOMB.load(storage)?.to_string() },
+        amount: Uint128::from(ETHER)
+    };
+    Ok(simulate(querier, pair, &offer_asset)?.return_amount)
+}
 pub fn get_reserve(storage: &dyn Storage) -> StdResult<Uint128> {
     Ok(SEIGNIORAGE_SAVED.load(storage)?)
 }
@@ -147,7 +236,7 @@ pub fn get_burnable_tomb_left(storage: &dyn Storage, querier: &QuerierWrapper) -
 
         if bond_max_supply > bond_supply {
             let max_mintable_bond = bond_max_supply - bond_supply;
-            let max_burnable_tomb = max_mintable_bond * tomb_price / Uint128::from(ETHER);
+            let max_burnable_tomb = max_mintable_bond.multiply_ratio(tomb_price, ETHER);
             let epoch_supply_contract_left = EPOCH_SUPPLY_CONTRACTION_LEFT.load(storage)?;
             
             if epoch_supply_contract_left > max_burnable_tomb {
@@ -171,7 +260,7 @@ pub fn get_redeemable_bonds(storage: &dyn Storage, querier: &QuerierWrapper, env
 
         let rate = get_bond_premium_rate(storage, querier)?;
         if rate > Uint128::zero() {
-            redeemable_bonds = total_tomb * Uint128::from(ETHER) / rate;
+            redeemable_bonds = total_tomb.multiply_ratio(ETHER, rate);
         }
     }
     Ok(redeemable_bonds)
@@ -182,30 +271,85 @@ pub fn get_bond_discount_rate(storage: &dyn Storage, querier: &QuerierWrapper) -
     let tomb_price_one = TOMB_PRICE_ONE.load(storage)?;
     let mut rate = Uint128::zero();
     if tomb_price <=  tomb_price_one {
-        let discount_percent = DISCOUNT_PERCENT.load(storage)?;
-        if discount_percent == Uint128::zero() {
-            // no discount
-            rate = tomb_price_one;
-        } else {
-            let bond_amount = tomb_price_one * Uint128::from(ETHER) / tomb_price;
-            let discount_amount = (bond_amount - tomb_price_one) * discount_percent / Uint128::from(10_000u128);
-            rate = tomb_price_one + discount_amount;
-            
-            let max_discount_rate = MAX_DISCOUNT_RATE.load(storage)?;
-            if max_discount_rate > Uint128::zero() && rate > max_discount_rate {
-                rate = max_discount_rate;
+        rate = match get_bond_pricing(storage)? {
+            BondPricing::Linear {} => get_linear_discount_rate(storage, tomb_price)?,
+            BondPricing::PiecewiseLinear { discount, .. } => interpolate_rate(&discount, tomb_price),
+            BondPricing::DebtRatio { taper_from_percent } => {
+                let discount_amount = get_linear_discount_rate(storage, tomb_price)? - tomb_price_one;
+                let max_debt_ratio_percent = MAX_DEBT_RATIO_PERCENT.load(storage)?;
+                let debt_ratio_percent = get_debt_ratio_percent(storage, querier)?;
+                if debt_ratio_percent >= max_debt_ratio_percent {
+                    tomb_price_one
+                } else if debt_ratio_percent <= taper_from_percent {
+                    tomb_price_one + discount_amount
+                } else {
+                    tomb_price_one + discount_amount.multiply_ratio(
+                        max_debt_ratio_percent - debt_ratio_percent, max_debt_ratio_percent - taper_from_percent)
+                }
             }
+        };
+
+        let max_discount_rate = MAX_DISCOUNT_RATE.load(storage)?;
+        if max_discount_rate > Uint128::zero() && rate > max_discount_rate {
+            rate = max_discount_rate;
         }
     }
     Ok(rate)
 }
 
+fn get_linear_discount_rate(storage: &dyn Storage, tomb_price: Uint128) -> StdResult<Uint128> {
+    let tomb_price_one = TOMB_PRICE_ONE.load(storage)?;
+    let discount_percent = DISCOUNT_PERCENT.load(storage)?;
+    if discount_percent == Uint128::zero() {
+        // no discount
+        return Ok(tomb_price_one);
+    }
+    let bond_amount = tomb_price_one.multiply_ratio(ETHER, tomb_price);
+    let discount_amount = (bond_amount - tomb_price_one) * discount_percent / Uint128::from(10_000u128);
+    Ok(tomb_price_one + discount_amount)
+}
+
+pub fn get_bond_pricing(storage: &dyn Storage) -> StdResult<BondPricing> {
+    Ok(BOND_PRICING.may_load(storage)?.unwrap_or(BondPricing::Linear {}))
+}
+
+// rate at `price` on the line through the surrounding points
+pub fn interpolate_rate(points: &[PricePoint], price: Uint128) -> Uint128 {
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Uint128::zero()
+    };
+    if price <= first.price {
+        return first.rate;
+    }
+    match points.windows(2).find(|pair| price <= pair[1].price) {
+        Some(pair) => {
+            let (low, high) = (&pair[0], &pair[1]);
+            let progress = (price - low.price, high.price - low.price);
+            if high.rate >= low.rate {
+                low.rate + (high.rate - low.rate).multiply_ratio(progress.0, progress.1)
+            } else {
+                low.rate - (low.rate - high.rate).multiply_ratio(progress.0, progress.1)
+            }
+        },
+        None => last.rate
+    }
+}
+
 pub fn get_bond_premium_rate(storage: &dyn Storage, querier: &QuerierWrapper) -> StdResult<Uint128> {
     let tomb_price = get_tomb_price(storage, querier)?;
     let tomb_price_ceiling = TOMB_PRICE_CEILING.load(storage)?;
     let mut rate = Uint128::zero();
 
     if tomb_price > tomb_price_ceiling {
+        if let BondPricing::PiecewiseLinear { premium, .. } = get_bond_pricing(storage)? {
+            rate = interpolate_rate(&premium, tomb_price);
+            let max_premium_rate = MAX_PREMIUM_RATE.load(storage)?;
+            if max_premium_rate > Uint128::zero() && rate > max_premium_rate {
+                rate = max_premium_rate;
+            }
+            return Ok(rate);
+        }
         let tomb_price_one = TOMB_PRICE_ONE.load(storage)?;
         let premium_threshold = PREMIUM_THRESHOLD.load(storage)?;
         let tomb_price_premium_threshold = tomb_price_one * premium_threshold / Uint128::from(100u128);
@@ -228,6 +372,15 @@ pub fn get_bond_premium_rate(storage: &dyn Storage, querier: &QuerierWrapper) ->
     Ok(rate)
 }
 
+// TBOND supply over the circulating TOMB in basis points, at the max while none circulates
+pub fn get_debt_ratio_percent(storage: &dyn Storage, querier: &QuerierWrapper) -> StdResult<Uint128> {
+    let tomb_supply = get_tomb_circulating_supply(storage, querier)?;
+    if tomb_supply.is_zero() {
+        return MAX_DEBT_RATIO_PERCENT.load(storage);
+    }
+    Ok(get_total_supply(querier, TBOND.load(storage)?)?.multiply_ratio(10_000u128, tomb_supply))
+}
+
 pub fn get_tomb_circulating_supply(storage: &dyn Storage, querier: &QuerierWrapper) -> StdResult<Uint128>{
     let tomb = TOMB.load(storage)?;
     let total_supply: Uint128 = querier.query_wasm_smart(
@@ -244,10 +397,390 @@ pub fn get_tomb_circulating_supply(storage: &dyn Storage, querier: &QuerierWrapp
     Ok(total_supply - balance_excluded)
 }
 
+// amounts of a Masonry allocation going to the seigniorage recipients, zero amounts skipped
+pub fn get_seigniorage_shares(storage: &dyn Storage, amount: Uint128) -> StdResult<Vec<(SeigniorageRecipient, Uint128)>> {
+    Ok(SEIGNIORAGE_RECIPIENTS.may_load(storage)?.unwrap_or_default()
+        .into_iter()
+        .map(|recipient| {
+            let share = amount * recipient.share / Uint128::from(10_000u128);
+            (recipient, share)
+        })
+        .filter(|(_, share)| !share.is_zero())
+        .collect())
+}
+
+pub fn get_seigniorage_recipients(storage: &dyn Storage) -> StdResult<Vec<SeigniorageRecipientResponse>> {
+    SEIGNIORAGE_RECIPIENTS.may_load(storage)?.unwrap_or_default()
+        .into_iter()
+        .map(|recipient| Ok(SeigniorageRecipientResponse {
+            paid: SEIGNIORAGE_PAID.may_load(storage, &recipient.name)?.unwrap_or_default(),
+            name: recipient.name,
+            address: recipient.address,
+            share: recipient.share,
+        }))
+        .collect()
+}
+
+// expansion cap of the highest supply tier reached, the last cap applied otherwise
+pub fn get_max_supply_expansion_percent(storage: &dyn Storage, tomb_supply: Uint128) -> StdResult<Uint128> {
+    let tier = get_supply_tiers(storage)?
+        .into_iter()
+        .rev()
+        .find(|tier| tomb_supply >= tier.supply);
+    match tier {
+        Some(tier) => Ok(tier.max_expansion_percent),
+        None => MAX_SUPPLY_EXPANSION_PERCENT.load(storage)
+    }
+}
+
+pub fn get_supply_tiers(storage: &dyn Storage) -> StdResult<Vec<SupplyTier>> {
+    let supply_tiers = SUPPLY_TIERS.load(storage)?;
+    let max_expansion_tiers = MAX_EXPANSION_TIERS.load(storage)?;
+    Ok(supply_tiers.into_iter()
+        .zip(max_expansion_tiers)
+        .map(|(supply, max_expansion_percent)| SupplyTier { supply, max_expansion_percent })
+        .collect())
+}
+
+// Seigniorage allocation of `epoch` at the current TWAP, see try_allocate_seigniorage
+pub fn get_epoch_allocation(
+    storage: &dyn Storage,
+    querier: &QuerierWrapper,
+    epoch: Uint128,
+    epoch_supply_contraction_left: Uint128
+) -> StdResult<EpochRecord> {
+    let tomb_price = get_tomb_price(storage, querier)?;
+    let seigniorage_saved = SEIGNIORAGE_SAVED.load(storage)?;
+    let tomb_supply = get_tomb_circulating_supply(storage, querier)? - seigniorage_saved;
+
+    let to_bond_treasury = tomb_supply * BOND_SUPPLY_EXPANSION_PERCENT.load(storage)? / Uint128::from(10_000u128);
+    let mut record = EpochRecord {
+        epoch: epoch.u128() as u64,
+        tomb_price,
+        tomb_supply,
+        bootstrap: epoch < BOOTSTRAP_EPOCHS.load(storage)?,
+        expansion_percent: Uint128::zero(),
+        to_masonry: Uint128::zero(),
+        to_recipients: vec![],
+        to_bond_treasury: get_bond_treasury_mint_amount(storage, querier, to_bond_treasury)?,
+        to_reserve: Uint128::zero(),
+        to_keeper: Uint128::zero(),
+        circuit_breaker_tripped: false,
+        epoch_supply_contraction_left,
+    };
+    let breaker = CIRCUIT_BREAKER.may_load(storage)?;
+    if let Some(breaker) = &breaker {
+        let previous_price = PREVIOUS_EPOCH_TOMB_PRICE.may_load(storage)?.unwrap_or_default();
+        record.circuit_breaker_tripped = is_price_move_above(previous_price, tomb_price, breaker.max_price_move);
+    }
+
+    let saved_for_masonry = if record.bootstrap {
+        // 28 first epochs with 4.5% expansion
+        record.expansion_percent = BOOTSTRAP_SUPPLY_EXPANSION_PERCENT.load(storage)?;
+        tomb_supply * record.expansion_percent / Uint128::from(10_000u128)
+    } else if tomb_price > TOMB_PRICE_CEILING.load(storage)? {
+        // Expansion ($TOMB Price > 1 $FTM): there is some seigniorage to be allocated
+        let bond_supply = get_total_supply(querier, TBOND.load(storage)?)?;
+        let mut percentage = tomb_price - TOMB_PRICE_ONE.load(storage)?;
+        let mut mse = get_max_supply_expansion_percent(storage, tomb_supply)? * Uint128::from((10u64).pow(14u32));
+        if let Some(breaker) = breaker.filter(|_| record.circuit_breaker_tripped) {
+            mse = std::cmp::min(mse, breaker.tripped_expansion_percent * Uint128::from((10u64).pow(14u32)));
+        }
+        if percentage > mse {
+            percentage = mse;
+        }
+        record.expansion_percent = percentage.multiply_ratio(10_000u128, ETHER);
+
+        let seigniorage = tomb_supply.multiply_ratio(percentage, ETHER);
+        if seigniorage_saved >= bond_supply * BOND_DEPLETION_FLOOR_PERCENT.load(storage)? / Uint128::from(10_000u128) {
+            // saved enough to pay debt, mint as usual rate
+            seigniorage
+        } else {
+            // have not saved enough to pay debt, mint more
+            let saved_for_masonry = seigniorage * SEIGNIORAGE_EXPANSION_FLOOR_PERCENT.load(storage)? / Uint128::from(10_000u128);
+            let mut saved_for_bond = seigniorage - saved_for_masonry;
+            let minting_factor = MINTING_FACTOR_FOR_PAYING_DEBT.load(storage)?;
+            if minting_factor > Uint128::zero() {
+                saved_for_bond = saved_for_bond * minting_factor / Uint128::from(10_000u128);
+            }
+            record.to_reserve = saved_for_bond;
+            saved_for_masonry
+        }
+    } else {
+        Uint128::zero()
+    };
+
+    let mut to_masonry = saved_for_masonry;
+    for (recipient, amount) in get_seigniorage_shares(storage, saved_for_masonry)? {
+        to_masonry -= amount;
+        record.to_recipients.push(SeigniorageShare { name: recipient.name, amount });
+    }
+    record.to_masonry = to_masonry;
+    record.to_keeper = get_keeper_bounty_amount(storage, saved_for_masonry + record.to_reserve)?;
+    Ok(record)
+}
+
+// the first epoch has no previous price to compare with
+pub fn is_price_move_above(previous_price: Uint128, price: Uint128, max_price_move: Uint128) -> bool {
+    if previous_price.is_zero() {
+        return false;
+    }
+    let price_move = if price > previous_price { price - previous_price } else { previous_price - price };
+    price_move.multiply_ratio(10_000u128, previous_price) > max_price_move
+}
+
+// bounty for an allocation minting `expansion` TOMB, never more than it mints so
+// contraction epochs pay nothing
+pub fn get_keeper_bounty_amount(storage: &dyn Storage, expansion: Uint128) -> StdResult<Uint128> {
+    match KEEPER_BOUNTY.may_load(storage)? {
+        Some(KeeperBounty::Fixed { amount }) => Ok(std::cmp::min(amount, expansion)),
+        Some(KeeperBounty::Percent { percent, cap }) => Ok(std::cmp::min(expansion * percent / Uint128::from(10_000u128), cap)),
+        None => Ok(Uint128::zero())
+    }
+}
+
+pub fn get_keeper_bounty(storage: &dyn Storage, querier: &QuerierWrapper, env: &Env) -> StdResult<KeeperBountyResponse> {
+    Ok(KeeperBountyResponse {
+        bounty: KEEPER_BOUNTY.may_load(storage)?,
+        next_bounty: get_allocation_simulation(storage, querier, env)?.to_keeper,
+    })
+}
+
+// allocation of the next epoch, after its contraction allowance is refilled
+pub fn get_allocation_simulation(storage: &dyn Storage, querier: &QuerierWrapper, env: &Env) -> StdResult<EpochRecord> {
+    let epoch = get_next_epoch(storage, env)?;
+    let epoch_supply_contraction_left = get_epoch_supply_contraction(storage, querier)?;
+    get_epoch_allocation(storage, querier, epoch, epoch_supply_contraction_left)
+}
+
+// TOMB to mint so the bond treasury has `amount` for sale, on top of what is still unsold
+pub fn get_bond_treasury_mint_amount(storage: &dyn Storage, querier: &QuerierWrapper, amount: Uint128) -> StdResult<Uint128> {
+    let tomb_for_sale: Uint128 = querier.query_wasm_smart(
+        BOND_TREASURY.load(storage)?,
+        &BondTreasuryQuery::TombForSale {  }
+    )?;
+    Ok(amount.saturating_sub(tomb_for_sale))
+}
+
+pub fn get_epoch_history(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u32>
+) -> StdResult<Vec<EpochRecord>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    EPOCH_HISTORY
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, record)| record))
+        .collect()
+}
+
+pub fn get_queued_transactions(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u32>
+) -> StdResult<Vec<QueuedTransaction>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    QUEUED_TRANSACTIONS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, transaction)| transaction))
+        .collect()
+}
+
+// parameter changes, operator handovers and token recoveries that wait for the timelock delay once it is enabled
+// (Initialize is refused outright once it ran)
+pub fn is_timelocked(msg: &ExecuteMsg) -> bool {
+    matches!(msg,
+        ExecuteMsg::ProposeOperator { .. }
+        | ExecuteMsg::SetMasonry { .. }
+        | ExecuteMsg::SetPeriod { .. }
+        | ExecuteMsg::SetBondTreasury { .. }
+        | ExecuteMsg::SetTombOracle { .. }
+        | ExecuteMsg::SetTombPriceCeiling { .. }
+        | ExecuteMsg::SetMaxSupplyExpansionPercents { .. }
+        | ExecuteMsg::SetSupplyTiersEntry { .. }
+        | ExecuteMsg::SetMaxExpansionTiersEntry { .. }
+        | ExecuteMsg::SetSupplyTiers { .. }
+        | ExecuteMsg::SetBondDepletionFloorPercent { .. }
+        | ExecuteMsg::SetMaxSupplyContractionPercent { .. }
+        | ExecuteMsg::SetMaxDebtRatioPercent { .. }
+        | ExecuteMsg::SetBootstrap { .. }
+        | ExecuteMsg::SetSeigniorageRecipients { .. }
+        | ExecuteMsg::SetMaxDiscountRate { .. }
+        | ExecuteMsg::SetMaxPremiumRate { .. }
+        | ExecuteMsg::SetDiscountPercent { .. }
+        | ExecuteMsg::SetPremiumThreshold { .. }
+        | ExecuteMsg::SetPremiumPercent { .. }
+        | ExecuteMsg::SetMintingFactorForPayingDebt { .. }
+        | ExecuteMsg::SetBondSupplyExpansionPercent { .. }
+        | ExecuteMsg::AddExcludedAddress { .. }
+        | ExecuteMsg::RemoveExcludedAddress { .. }
+        | ExecuteMsg::SetKeeperBounty { .. }
+        | ExecuteMsg::SetCatchUpPolicy { .. }
+        | ExecuteMsg::SetCircuitBreaker { .. }
+        | ExecuteMsg::SetBondPricing { .. }
+        | ExecuteMsg::SetTimelockDelay { .. }
+        | ExecuteMsg::SetGuardian { .. }
+        | ExecuteMsg::GovernanceRecoverUnsupported { .. }
+        | ExecuteMsg::MasonryProposeOperator { .. }
+        | ExecuteMsg::MasonryAcceptOperator { .. }
+        | ExecuteMsg::MasonrySetLockup { .. }
+        | ExecuteMsg::MasonrySetGuardian { .. }
+        | ExecuteMsg::MasonryGovernanceRecoverUnsupported { .. }
+    )
+}
+
+pub fn get_excluded_from_total_supply(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+    limit: Option<u32>
+) -> StdResult<Vec<Addr>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let mut excluded_from_totalsupply = EXCLUDED_FROM_TOTALSUPPLY.load(storage)?;
+    excluded_from_totalsupply.sort();
+    Ok(excluded_from_totalsupply.into_iter()
+        .filter(|excluded| match &start_after {
+            Some(start) => excluded > start,
+            None => true
+        })
+        .take(limit)
+        .collect())
+}
+
 pub fn get_total_supply(querier: &QuerierWrapper, token: Addr) -> StdResult<Uint128>{
     let total_supply: Uint128 = querier.query_wasm_smart(
         token, 
         &BasisAssetQuery::TotalSupply {  }
     )?;
     Ok(total_supply)
-}
\ No newline at end of file
+}
+
+pub fn get_config(storage: &dyn Storage) -> StdResult<ConfigResponse> {
+    Ok(ConfigResponse {
+        operator: OPERATOR.may_load(storage)?,
+        tomb: TOMB.load(storage)?,
+        tbond: TBOND.load(storage)?,
+        tshare: TSHARE.load(storage)?,
+        masonry: MASONRY.load(storage)?,
+        bond_treasury: BOND_TREASURY.load(storage)?,
+        tomb_oracle: TOMB_ORACLE.load(storage)?,
+        start_time: START_TIME.load(storage)?,
+        period: PERIOD.load(storage)?,
+        tomb_price_one: TOMB_PRICE_ONE.load(storage)?,
+        tomb_price_ceiling: TOMB_PRICE_CEILING.load(storage)?,
+        supply_tiers: SUPPLY_TIERS.load(storage)?,
+        max_expansion_tiers: MAX_EXPANSION_TIERS.load(storage)?,
+        max_supply_expansion_percent: MAX_SUPPLY_EXPANSION_PERCENT.load(storage)?,
+        bond_depletion_floor_percent: BOND_DEPLETION_FLOOR_PERCENT.load(storage)?,
+        seigniorage_expansion_floor_percent: SEIGNIORAGE_EXPANSION_FLOOR_PERCENT.load(storage)?,
+        max_supply_contraction_percent: MAX_SUPPLY_CONTRACTION_PERCENT.load(storage)?,
+        max_debt_ratio_percent: MAX_DEBT_RATIO_PERCENT.load(storage)?,
+        bond_supply_expansion_percent: BOND_SUPPLY_EXPANSION_PERCENT.load(storage)?,
+        bootstrap_epochs: BOOTSTRAP_EPOCHS.load(storage)?,
+        bootstrap_supply_expansion_percent: BOOTSTRAP_SUPPLY_EXPANSION_PERCENT.load(storage)?,
+        max_discount_rate: MAX_DISCOUNT_RATE.load(storage)?,
+        max_premium_rate: MAX_PREMIUM_RATE.load(storage)?,
+        discount_percent: DISCOUNT_PERCENT.load(storage)?,
+        premium_threshold: PREMIUM_THRESHOLD.load(storage)?,
+        premium_percent: PREMIUM_PERCENT.load(storage)?,
+        minting_factor_for_paying_debt: MINTING_FACTOR_FOR_PAYING_DEBT.load(storage)?,
+        seigniorage_recipients: SEIGNIORAGE_RECIPIENTS.may_load(storage)?.unwrap_or_default(),
+    })
+}
+
+// the checks of BuyBonds but the target price
+pub fn quote_buy_bonds(storage: &dyn Storage, querier: &QuerierWrapper, env: &Env, tomb_amount: Uint128) -> StdResult<BondQuote> {
+    let unavailable = |reason| Ok(BondQuote::Unavailable { reason });
+    if PAUSE.is_paused(storage, &Operation::BuyBonds)? {
+        return unavailable(BondQuoteFailure::Paused {});
+    }
+    if Uint128::from(env.block.time.seconds()) < START_TIME.load(storage)? {
+        return unavailable(BondQuoteFailure::NotStartedYet {});
+    }
+    if tomb_amount.is_zero() {
+        return unavailable(BondQuoteFailure::ZeroAmount {});
+    }
+
+    let tomb_price = get_tomb_price(storage, querier)?;
+    if tomb_price >= TOMB_PRICE_ONE.load(storage)? {
+        return unavailable(BondQuoteFailure::PriceNotEligible { tomb_price });
+    }
+
+    let tomb_amount_left = EPOCH_SUPPLY_CONTRACTION_LEFT.load(storage)?;
+    if tomb_amount > tomb_amount_left {
+        return unavailable(BondQuoteFailure::NotEnoughContractionLeft { tomb_amount_left });
+    }
+
+    let rate = get_bond_discount_rate(storage, querier)?;
+    if rate.is_zero() {
+        return unavailable(BondQuoteFailure::InvalidRate {});
+    }
+
+    let bond_amount = tomb_amount.multiply_ratio(rate, ETHER);
+    let bond_max_supply = get_tomb_circulating_supply(storage, querier)? * MAX_DEBT_RATIO_PERCENT.load(storage)? / Uint128::from(10_000u128);
+    let bond_amount_left = bond_max_supply.saturating_sub(get_total_supply(querier, TBOND.load(storage)?)?);
+    if bond_amount > bond_amount_left {
+        return unavailable(BondQuoteFailure::OverMaxDebtRatio { bond_amount_left });
+    }
+    Ok(BondQuote::Amount { amount: bond_amount, tomb_price, rate })
+}
+
+// the checks of RedeemBonds but the target price
+pub fn quote_redeem_bonds(storage: &dyn Storage, querier: &QuerierWrapper, env: &Env, bond_amount: Uint128) -> StdResult<BondQuote> {
+    let unavailable = |reason| Ok(BondQuote::Unavailable { reason });
+    if PAUSE.is_paused(storage, &Operation::RedeemBonds)? {
+        return unavailable(BondQuoteFailure::Paused {});
+    }
+    if Uint128::from(env.block.time.seconds()) < START_TIME.load(storage)? {
+        return unavailable(BondQuoteFailure::NotStartedYet {});
+    }
+    if bond_amount.is_zero() {
+        return unavailable(BondQuoteFailure::ZeroAmount {});
+    }
+
+    let tomb_price = get_tomb_price(storage, querier)?;
+    if tomb_price <= TOMB_PRICE_ONE.load(storage)? {
+        return unavailable(BondQuoteFailure::PriceNotEligible { tomb_price });
+    }
+
+    let rate = get_bond_premium_rate(storage, querier)?;
+    if rate.is_zero() {
+        return unavailable(BondQuoteFailure::InvalidRate {});
+    }
+
+    let tomb_amount = bond_amount.multiply_ratio(rate, ETHER);
+    let tomb_balance = query_token_balance(querier, TOMB.load(storage)?, env.contract.address.clone())?;
+    if tomb_balance < tomb_amount {
+        return unavailable(BondQuoteFailure::NotEnoughBudget { tomb_balance });
+    }
+    Ok(BondQuote::Amount { amount: tomb_amount, tomb_price, rate })
+}
+
+pub fn get_bond_market(storage: &dyn Storage, querier: &QuerierWrapper, env: Env) -> StdResult<BondMarketResponse> {
+    Ok(BondMarketResponse {
+        tomb_price: get_tomb_price(storage, querier)?,
+        spot_price: get_tomb_spot_price(storage, querier)?,
+        discount_rate: get_bond_discount_rate(storage, querier)?,
+        premium_rate: get_bond_premium_rate(storage, querier)?,
+        debt_ratio_percent: get_debt_ratio_percent(storage, querier)?,
+        max_debt_ratio_percent: MAX_DEBT_RATIO_PERCENT.load(storage)?,
+        epoch_supply_contraction_left: EPOCH_SUPPLY_CONTRACTION_LEFT.load(storage)?,
+        burnable_tomb_left: get_burnable_tomb_left(storage, querier)?,
+        treasury_tomb_balance: query_token_balance(querier, TOMB.load(storage)?, env.contract.address.clone())?,
+        redeemable_bonds: get_redeemable_bonds(storage, querier, env)?,
+    })
+}
+
+pub fn get_state(storage: &dyn Storage, querier: &QuerierWrapper) -> StdResult<StateResponse> {
+    Ok(StateResponse {
+        epoch: EPOCH.load(storage)?,
+        next_epoch_point: next_epoch_point(storage)?,
+        seigniorage_saved: SEIGNIORAGE_SAVED.load(storage)?,
+        epoch_supply_contraction_left: EPOCH_SUPPLY_CONTRACTION_LEFT.load(storage)?,
+        previous_epoch_tomb_price: PREVIOUS_EPOCH_TOMB_PRICE.may_load(storage)?.unwrap_or_default(),
+        tomb_circulating_supply: get_tomb_circulating_supply(storage, querier)?,
+    })
+}
@@ -4,7 +4,7 @@ use std::fs::create_dir_all;
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
 use BondTreasury::msg::{
-    QueryMsg,
+    ExecuteMsg, InstantiateMsg, QueryMsg, Asset, VestingSchedule,
 };
 
 fn main() {
@@ -13,5 +13,9 @@ fn main() {
     create_dir_all(&out_dir).unwrap();
     remove_schemas(&out_dir).unwrap();
 
+    export_schema(&schema_for!(InstantiateMsg), &out_dir);
+    export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(Asset), &out_dir);
+    export_schema(&schema_for!(VestingSchedule), &out_dir);
 }
@@ -3,8 +3,79 @@ use serde::{Deserialize, Serialize};
 
 use cosmwasm_std::{Uint128, Addr};
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstantiateMsg {
+    pub tomb: Addr,
+    pub tomb_oracle: Addr,
+    pub treasury: Addr,
+    pub dao: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    Bond{token: Addr, amount: Uint128},
+    ClaimRewards{},
+    // Treasury only, puts the TOMB it just sent up for sale
+    TopUp{amount: Uint128},
+    SetTomb{tomb: Addr},
+    SetTombOracle{tomb_oracle: Addr},
+    SetTreasury{treasury: Addr},
+    SetDao{dao: Addr},
+    SetAsset{
+        token: Addr,
+        is_added: bool,
+        multiplier: Uint128,
+        oracle: Addr,
+        is_lp: bool,
+        pair: Addr
+    },
+    SetBondParameter{
+        primary_threshold: Uint128,
+        primary_factor: Uint128,
+        second_threshold: Uint128,
+        second_factor: Uint128,
+        vesting_period: Uint128
+    },
+    ProposeOperator{operator: Addr},
+    AcceptOperator{},
+    CancelProposal{},
+    RenounceOperator{},
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    TotalVested{}
+    TotalVested{},
+    // TOMB the Treasury topped up that is not sold yet
+    TombForSale{},
+    GetTombReturn{token: Addr, amount: Uint128},
+    GetBondPremium{},
+    GetTombPrice{},
+    GetTokenPrice{token: Addr},
+    ClaimableTomb{account: Addr},
+    GetVesting{account: Addr},
+    GetAsset{token: Addr},
+    PendingOperator{},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Asset {
+    pub is_added: bool,
+    pub multiplier: Uint128,
+    pub oracle: Addr,
+    pub is_lp: bool,
+    pub pair: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VestingSchedule {
+    pub amount: Uint128,
+    pub period: Uint128,
+    pub end: Uint128,
+    pub claimed: Uint128,
+    pub last_claimed: Uint128
 }
@@ -8,6 +8,13 @@ pub struct InstantiateMsg {
     pub POOLSTARTTIME: Uint128
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {
+    // snapshots moved out of the 0.0.1 history per migration, migrate again with
+    // the same code until none are left
+    pub snapshot_limit: Option<u32>
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
@@ -16,9 +23,19 @@ pub enum ExecuteMsg {
         share: Addr,
         treasury: Addr,
     },
-    SetOperator {
+    ProposeOperator {
         operator: Addr
     },
+    AcceptOperator{ },
+    CancelProposal{ },
+    RenounceOperator{ },
+    // None leaves nobody able to pause
+    SetGuardian{
+        guardian: Option<Addr>
+    },
+    // guardian only, Exit stays available
+    Pause{ operations: Vec<Operation> },
+    Unpause{ operations: Vec<Operation> },
     SetLockUp {
         withdraw_lockup_epochs: Uint128,
         reward_lockup_epochs: Uint128
@@ -39,6 +56,8 @@ pub enum ExecuteMsg {
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     Operator{ },
+    PendingOperator{ },
+    PauseStatus{ },
     
     LatestSnapshotIndex{ },
     GetLastSnapshotIndexOf{ mason: Addr },
@@ -54,6 +73,15 @@ pub enum QueryMsg {
 
 }
 
+// What the guardian can pause
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    Stake,
+    Withdraw,
+    ClaimReward
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Masonseat {
     pub last_snapshot_index: Uint128,
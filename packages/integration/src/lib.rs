@@ -0,0 +1,3 @@
+pub mod pair;
+pub mod suite;
+pub mod token;
@@ -0,0 +1,79 @@
+// Stand-in for a terraswap pair. It holds no liquidity: the reserves are set
+// directly by the test so the Oracle can be fed exact prices. Simulations quote
+// at the spot price of those reserves, without spread or commission.
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult, Uint128
+};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use terraswap::asset::{Asset, AssetInfo};
+use terraswap::pair::{PoolResponse, QueryMsg, SimulationResponse};
+
+pub const ASSET_INFOS: Item<[AssetInfo; 2]> = Item::new("asset infos");
+pub const RESERVES: Item<[Uint128; 2]> = Item::new("reserves");
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct InstantiateMsg {
+    pub asset_infos: [AssetInfo; 2],
+    pub reserves: [Uint128; 2],
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    SetReserves { reserves: [Uint128; 2] },
+}
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    ASSET_INFOS.save(deps.storage, &msg.asset_infos)?;
+    RESERVES.save(deps.storage, &msg.reserves)?;
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        ExecuteMsg::SetReserves { reserves } => RESERVES.save(deps.storage, &reserves)?,
+    }
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    let asset_infos = ASSET_INFOS.load(deps.storage)?;
+    let reserves = RESERVES.load(deps.storage)?;
+    match msg {
+        QueryMsg::Pool {} => to_binary(&PoolResponse {
+            assets: [
+                Asset { info: asset_infos[0].clone(), amount: reserves[0] },
+                Asset { info: asset_infos[1].clone(), amount: reserves[1] },
+            ],
+            total_share: Uint128::zero(),
+        }),
+        QueryMsg::Simulation { offer_asset } => {
+            let (offer_pool, ask_pool) = if offer_asset.info == asset_infos[0] {
+                (reserves[0], reserves[1])
+            } else if offer_asset.info == asset_infos[1] {
+                (reserves[1], reserves[0])
+            } else {
+                return Err(StdError::generic_err("asset is not in the pair"));
+            };
+            to_binary(&SimulationResponse {
+                return_amount: offer_asset.amount.multiply_ratio(ask_pool, offer_pool),
+                spread_amount: Uint128::zero(),
+                commission_amount: Uint128::zero(),
+            })
+        }
+        _ => Err(StdError::generic_err("not supported by the stand-in pair")),
+    }
+}
@@ -0,0 +1,370 @@
+use cosmwasm_std::testing::{mock_env, MockApi, MockStorage};
+use cosmwasm_std::{to_binary, Addr, DepsMut, Empty, Env, MessageInfo, Response, Uint128};
+use std::cell::RefCell;
+use cw_multi_test::{App, AppResponse, BankKeeper, Contract, ContractWrapper, Executor};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use terraswap::asset::AssetInfo;
+use BasisAsset::msg::{BalanceResponse, InitialBalance, QueryMsg as BasisAssetQuery};
+use IMasonry::msg::{ExecuteMsg as MasonryMsg, InstantiateMsg as MasonryInstantiateMsg};
+use Oracle::msg::{ExecuteMsg as OracleMsg, InstantiateMsg as OracleInstantiateMsg};
+use Treasury::msg::{ExecuteMsg as TreasuryMsg, InstantiateMsg as TreasuryInstantiateMsg, QueryMsg as TreasuryQuery};
+use Treasury::ContractError as TreasuryError;
+use BondTreasury::msg::InstantiateMsg as BondTreasuryInstantiateMsg;
+
+use crate::{pair, token};
+
+pub type AnyResult<T> = Result<T, anyhow::Error>;
+
+pub const ETHER: u128 = 1_000_000_000_000_000_000u128;
+// Treasury and Oracle epoch length
+pub const PERIOD: u64 = 21_600;
+// Virtual TOMB reserve of the stand-in pair, the quote reserve follows the price
+pub const POOL_DEPTH: u128 = 100_000;
+
+pub fn ether(amount: u128) -> Uint128 {
+    Uint128::from(amount * ETHER)
+}
+
+// TOMB price in percent of the peg, e.g. price(120) is 1.20
+pub fn price(percent: u128) -> Uint128 {
+    Uint128::from(percent * ETHER / 100)
+}
+
+// Value of an attribute emitted by the given contract
+pub fn attribute(res: &AppResponse, contract: &Addr, key: &str) -> Option<String> {
+    res.events.iter()
+        .filter(|event| event.ty == "wasm")
+        .filter(|event| event.attributes.iter().any(|attr| attr.key == "_contract_addr" && attr.value == contract.as_str()))
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.clone())
+}
+
+// Attributes of every custom event of the given type emitted by the contract
+pub fn events(res: &AppResponse, contract: &Addr, ty: &str) -> Vec<Vec<(String, String)>> {
+    let ty = format!("wasm-{}", ty);
+    res.events.iter()
+        .filter(|event| event.ty == ty)
+        .filter(|event| event.attributes.iter().any(|attr| attr.key == "_contract_addr" && attr.value == contract.as_str()))
+        .map(|event| event.attributes.iter()
+            .filter(|attr| attr.key != "_contract_addr")
+            .map(|attr| (attr.key.clone(), attr.value.clone()))
+            .collect())
+        .collect()
+}
+
+fn contract_token() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(token::execute, token::instantiate, token::query))
+}
+
+fn contract_pair() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(pair::execute, pair::instantiate, pair::query))
+}
+
+fn contract_erc20() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        ERC20::contract::execute,
+        ERC20::contract::instantiate,
+        ERC20::contract::query,
+    ))
+}
+
+fn contract_oracle() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        Oracle::contract::execute,
+        Oracle::contract::instantiate,
+        Oracle::query::query,
+    ))
+}
+
+fn contract_masonry() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        Masonry::contract::execute,
+        Masonry::contract::instantiate,
+        Masonry::query::query,
+    ))
+}
+
+thread_local! {
+    static TREASURY_RESPONSES: RefCell<Vec<Response>> = const { RefCell::new(Vec::new()) };
+}
+
+// Keeps every response of the Treasury so tests can look at the messages it dispatched
+fn treasury_execute_recorded(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: TreasuryMsg,
+) -> Result<Response, TreasuryError> {
+    let res = Treasury::contract::execute(deps, env, info, msg)?;
+    TREASURY_RESPONSES.with(|responses| responses.borrow_mut().push(res.clone()));
+    Ok(res)
+}
+
+// Response of the last successful Treasury execution
+pub fn last_treasury_response() -> Option<Response> {
+    TREASURY_RESPONSES.with(|responses| responses.borrow().last().cloned())
+}
+
+fn contract_treasury() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        treasury_execute_recorded,
+        Treasury::contract::instantiate,
+        Treasury::query::query,
+    ).with_reply(Treasury::contract::reply))
+}
+
+fn contract_bond_treasury() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        BondTreasuryContract::contract::execute,
+        BondTreasuryContract::contract::instantiate,
+        BondTreasuryContract::query::query,
+    ))
+}
+
+fn initial_balances(balances: &[(&str, u128)]) -> Vec<InitialBalance> {
+    balances
+        .iter()
+        .map(|(address, amount)| InitialBalance { address: address.to_string(), amount: ether(*amount) })
+        .collect()
+}
+
+// The whole protocol deployed on a simulated chain and wired the way it is on mainnet:
+// the Treasury operates TOMB, TBOND, TSHARE and the Masonry, and the Oracle tracks
+// the TOMB / WFTM pair. Genesis holdings:
+//   alice: 10_000 TOMB, 1_000 TBOND
+//   bob:   10_000 TOMB,   100 TSHARE
+//   carol:                100 TSHARE, staked in the Masonry so seigniorage can be allocated
+//   genesis pool: 5_000 TOMB (excluded from the circulating supply)
+pub struct Suite {
+    pub app: App,
+    pub start_time: u64,
+    pub admin: Addr,
+    pub keeper: Addr,
+    pub genesis_pool: Addr,
+    pub tomb: Addr,
+    pub tbond: Addr,
+    pub tshare: Addr,
+    pub wftm: Addr,
+    pub pair: Addr,
+    pub oracle: Addr,
+    pub masonry: Addr,
+    pub treasury: Addr,
+    pub bond_treasury: Addr,
+}
+
+impl Suite {
+    pub fn new() -> Self {
+        let env = mock_env();
+        let mut app = App::new(MockApi::default(), env.block.clone(), BankKeeper::new(), MockStorage::new());
+        let admin = Addr::unchecked("admin");
+        let genesis_pool = Addr::unchecked("genesis_pool");
+        let start_time = Uint128::from(env.block.time.seconds());
+
+        let token_id = app.store_code(contract_token());
+        let pair_id = app.store_code(contract_pair());
+        let erc20_id = app.store_code(contract_erc20());
+        let oracle_id = app.store_code(contract_oracle());
+        let masonry_id = app.store_code(contract_masonry());
+        let treasury_id = app.store_code(contract_treasury());
+        let bond_treasury_id = app.store_code(contract_bond_treasury());
+
+        let tomb = app.instantiate_contract(token_id, admin.clone(), &token::InstantiateMsg {
+            initial_balances: initial_balances(&[("alice", 10_000), ("bob", 10_000), ("genesis_pool", 5_000)]),
+        }, &[], "TOMB", None).unwrap();
+        let tbond = app.instantiate_contract(token_id, admin.clone(), &token::InstantiateMsg {
+            initial_balances: initial_balances(&[("alice", 1_000)]),
+        }, &[], "TBOND", None).unwrap();
+        let tshare = app.instantiate_contract(token_id, admin.clone(), &token::InstantiateMsg {
+            initial_balances: initial_balances(&[("bob", 100), ("carol", 100)]),
+        }, &[], "TSHARE", None).unwrap();
+        let wftm = app.instantiate_contract(erc20_id, admin.clone(), &ERC20::InstantiateMsg {
+            _startTime: start_time,
+            _communityFund: "community".to_string(),
+            _devFund: "dev".to_string(),
+        }, &[], "WFTM", None).unwrap();
+
+        let pair = app.instantiate_contract(pair_id, admin.clone(), &pair::InstantiateMsg {
+            asset_infos: [
+                AssetInfo::Token { contract_addr: tomb.to_string() },
+                AssetInfo::Token { contract_addr: wftm.to_string() },
+            ],
+            reserves: [ether(POOL_DEPTH), ether(POOL_DEPTH)],
+        }, &[], "TOMB-WFTM", None).unwrap();
+        let oracle = app.instantiate_contract(oracle_id, admin.clone(), &OracleInstantiateMsg {
+            pair: pair.clone(),
+            period: Uint128::from(PERIOD),
+            start_time,
+        }, &[], "Oracle", None).unwrap();
+
+        let treasury = app.instantiate_contract(treasury_id, admin.clone(),
+            &TreasuryInstantiateMsg {}, &[], "Treasury", None).unwrap();
+        let masonry = app.instantiate_contract(masonry_id, admin.clone(), &MasonryInstantiateMsg {
+            TOMB: tomb.to_string(),
+            POOLSTARTTIME: start_time,
+        }, &[], "Masonry", None).unwrap();
+        let bond_treasury = app.instantiate_contract(bond_treasury_id, admin.clone(), &BondTreasuryInstantiateMsg {
+            tomb: tomb.clone(),
+            tomb_oracle: oracle.clone(),
+            treasury: treasury.clone(),
+            dao: Addr::unchecked("dao"),
+        }, &[], "BondTreasury", None).unwrap();
+
+        app.execute_contract(admin.clone(), masonry.clone(), &MasonryMsg::Initialize {
+            tomb: tomb.clone(),
+            share: tshare.clone(),
+            treasury: treasury.clone(),
+        }, &[]).unwrap();
+        app.execute_contract(admin.clone(), treasury.clone(), &TreasuryMsg::Initialize {
+            tomb: tomb.clone(),
+            tbond: tbond.clone(),
+            tshare: tshare.clone(),
+            tomb_oracle: oracle.clone(),
+            masonry: masonry.clone(),
+            genesis_pool: genesis_pool.clone(),
+            bond_treasury: bond_treasury.clone(),
+            start_time,
+            period: Uint128::from(PERIOD),
+        }, &[]).unwrap();
+
+        // hand the protocol over to the Treasury
+        for asset in [&tomb, &tbond, &tshare] {
+            app.execute_contract(admin.clone(), asset.clone(), &token::ExecuteMsg::TransferOperator {
+                new_operator: treasury.to_string(),
+            }, &[]).unwrap();
+        }
+        app.execute_contract(admin.clone(), masonry.clone(),
+            &MasonryMsg::ProposeOperator { operator: treasury.clone() }, &[]).unwrap();
+        app.execute_contract(admin.clone(), treasury.clone(),
+            &TreasuryMsg::MasonryAcceptOperator {  }, &[]).unwrap();
+
+        let mut suite = Suite {
+            app,
+            start_time: start_time.u128() as u64,
+            admin,
+            keeper: Addr::unchecked("keeper"),
+            genesis_pool,
+            tomb,
+            tbond,
+            tshare,
+            wftm,
+            pair,
+            oracle,
+            masonry,
+            treasury,
+            bond_treasury,
+        };
+        suite.stake("carol", ether(100)).unwrap();
+        suite
+    }
+
+    // Moves the chain to the next epoch boundary
+    pub fn next_epoch(&mut self) {
+        self.app.update_block(|block| {
+            block.time = block.time.plus_seconds(PERIOD);
+            block.height += 1;
+        });
+    }
+
+    pub fn next_block(&mut self) {
+        self.app.update_block(|block| {
+            block.time = block.time.plus_seconds(5);
+            block.height += 1;
+        });
+    }
+
+    // Sets the spot price of TOMB in the pair, the Oracle picks it up on its next update
+    pub fn set_pair_price(&mut self, price: Uint128) {
+        let reserves = [ether(POOL_DEPTH), ether(POOL_DEPTH).multiply_ratio(price, ETHER)];
+        self.app.execute_contract(self.admin.clone(), self.pair.clone(),
+            &pair::ExecuteMsg::SetReserves { reserves }, &[]).unwrap();
+    }
+
+    pub fn update_oracle(&mut self) -> AnyResult<AppResponse> {
+        self.app.execute_contract(self.keeper.clone(), self.oracle.clone(), &OracleMsg::Update {}, &[])
+    }
+
+    pub fn treasury_execute(&mut self, sender: &str, msg: &TreasuryMsg) -> AnyResult<AppResponse> {
+        self.app.execute_contract(Addr::unchecked(sender), self.treasury.clone(), msg, &[])
+    }
+
+    pub fn masonry_execute(&mut self, sender: &str, msg: &MasonryMsg) -> AnyResult<AppResponse> {
+        self.app.execute_contract(Addr::unchecked(sender), self.masonry.clone(), msg, &[])
+    }
+
+    pub fn allocate_seigniorage(&mut self) -> AnyResult<AppResponse> {
+        let keeper = self.keeper.clone();
+        self.treasury_execute(keeper.as_str(), &TreasuryMsg::AllocateSeigniorage {})
+    }
+
+    pub fn approve(&mut self, owner: &str, token: &Addr, spender: &Addr, amount: Uint128) {
+        self.app.execute_contract(Addr::unchecked(owner), token.clone(), &token::ExecuteMsg::Approve {
+            spender: spender.to_string(),
+            amount,
+        }, &[]).unwrap();
+    }
+
+    // cw20 Send of `amount` to `contract` with the hook `msg`
+    pub fn send<T: Serialize>(&mut self, sender: &str, token: &Addr, contract: &Addr, amount: Uint128, msg: &T) -> AnyResult<AppResponse> {
+        self.app.execute_contract(Addr::unchecked(sender), token.clone(), &token::ExecuteMsg::Send {
+            contract: contract.to_string(),
+            amount,
+            msg: to_binary(msg)?,
+        }, &[])
+    }
+
+    pub fn buy_bonds(&mut self, sender: &str, tomb_amount: Uint128, target_price: Uint128) -> AnyResult<AppResponse> {
+        let (tomb, treasury) = (self.tomb.clone(), self.treasury.clone());
+        self.approve(sender, &tomb, &treasury, tomb_amount);
+        self.treasury_execute(sender, &TreasuryMsg::BuyBonds { tomb_amount, target_price })
+    }
+
+    pub fn redeem_bonds(&mut self, sender: &str, bond_amount: Uint128, target_price: Uint128) -> AnyResult<AppResponse> {
+        let (tbond, treasury) = (self.tbond.clone(), self.treasury.clone());
+        self.approve(sender, &tbond, &treasury, bond_amount);
+        self.treasury_execute(sender, &TreasuryMsg::RedeemBonds { bond_amount, target_price })
+    }
+
+    pub fn stake(&mut self, sender: &str, amount: Uint128) -> AnyResult<AppResponse> {
+        let (tshare, masonry) = (self.tshare.clone(), self.masonry.clone());
+        self.approve(sender, &tshare, &masonry, amount);
+        self.app.execute_contract(Addr::unchecked(sender), masonry, &MasonryMsg::Stake { amount }, &[])
+    }
+
+    pub fn withdraw(&mut self, sender: &str, amount: Uint128) -> AnyResult<AppResponse> {
+        self.app.execute_contract(Addr::unchecked(sender), self.masonry.clone(), &MasonryMsg::Withdraw { amount }, &[])
+    }
+
+    pub fn claim_reward(&mut self, sender: &str) -> AnyResult<AppResponse> {
+        self.app.execute_contract(Addr::unchecked(sender), self.masonry.clone(), &MasonryMsg::ClaimReward {}, &[])
+    }
+
+    pub fn query<T: DeserializeOwned, M: Serialize>(&self, contract: &Addr, msg: &M) -> T {
+        self.app.wrap().query_wasm_smart(contract, msg).unwrap()
+    }
+
+    pub fn treasury_query<T: DeserializeOwned>(&self, msg: &TreasuryQuery) -> T {
+        self.query(&self.treasury, msg)
+    }
+
+    pub fn balance(&self, token: &Addr, account: &str) -> Uint128 {
+        let res: BalanceResponse = self.query(token, &BasisAssetQuery::Balance { address: account.to_string() });
+        res.balance
+    }
+
+    pub fn total_supply(&self, token: &Addr) -> Uint128 {
+        self.query(token, &BasisAssetQuery::TotalSupply {})
+    }
+
+    pub fn epoch(&self) -> Uint128 {
+        self.treasury_query(&TreasuryQuery::Epoch {})
+    }
+}
+
+impl Default for Suite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
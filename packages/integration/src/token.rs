@@ -0,0 +1,157 @@
+// Stand-in for the TOMB, TBOND and TSHARE basis assets. It speaks the BasisAsset
+// interface the Treasury relies on: cw20 style balances, allowances and sends, plus
+// an operator that alone may mint and burn from other accounts.
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
+    StdResult, Storage, Uint128
+};
+use cw20::Cw20ReceiveMsg;
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use BasisAsset::msg::{AllowanceResponse, BalanceResponse, InitialBalance, QueryMsg};
+
+pub const OPERATOR: Item<Addr> = Item::new("operator");
+pub const TOTAL_SUPPLY: Item<Uint128> = Item::new("total supply");
+pub const BALANCES: Map<Addr, Uint128> = Map::new("balances");
+pub const ALLOWANCES: Map<(Addr, Addr), Uint128> = Map::new("allowances");
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct InstantiateMsg {
+    pub initial_balances: Vec<InitialBalance>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    Approve { spender: String, amount: Uint128 },
+    Transfer { recipient: String, amount: Uint128 },
+    Send { contract: String, amount: Uint128, msg: Binary },
+    TransferFrom { owner: String, recipient: String, amount: Uint128 },
+    Burn { amount: Uint128 },
+    BurnFrom { from: String, amount: Uint128 },
+    Mint { recipient: String, amount: Uint128 },
+    TransferOperator { new_operator: String },
+}
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    OPERATOR.save(deps.storage, &info.sender)?;
+    TOTAL_SUPPLY.save(deps.storage, &Uint128::zero())?;
+    for balance in msg.initial_balances {
+        mint(deps.storage, Addr::unchecked(balance.address), balance.amount)?;
+    }
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        ExecuteMsg::Approve { spender, amount } => {
+            ALLOWANCES.save(deps.storage, (info.sender, Addr::unchecked(spender)), &amount)?;
+        }
+        ExecuteMsg::Transfer { recipient, amount } => {
+            transfer(deps.storage, info.sender, Addr::unchecked(recipient), amount)?;
+        }
+        ExecuteMsg::Send { contract, amount, msg } => {
+            transfer(deps.storage, info.sender.clone(), Addr::unchecked(&contract), amount)?;
+            let msg = Cw20ReceiveMsg { sender: info.sender.to_string(), amount, msg };
+            return Ok(Response::new().add_message(msg.into_cosmos_msg(contract)?));
+        }
+        ExecuteMsg::TransferFrom { owner, recipient, amount } => {
+            let owner = Addr::unchecked(owner);
+            spend_allowance(deps.storage, owner.clone(), info.sender, amount)?;
+            transfer(deps.storage, owner, Addr::unchecked(recipient), amount)?;
+        }
+        ExecuteMsg::Burn { amount } => {
+            burn(deps.storage, info.sender, amount)?;
+        }
+        ExecuteMsg::BurnFrom { from, amount } => {
+            check_onlyoperator(deps.storage, &info.sender)?;
+            let from = Addr::unchecked(from);
+            spend_allowance(deps.storage, from.clone(), info.sender, amount)?;
+            burn(deps.storage, from, amount)?;
+        }
+        ExecuteMsg::Mint { recipient, amount } => {
+            check_onlyoperator(deps.storage, &info.sender)?;
+            mint(deps.storage, Addr::unchecked(recipient), amount)?;
+        }
+        ExecuteMsg::TransferOperator { new_operator } => {
+            check_onlyoperator(deps.storage, &info.sender)?;
+            OPERATOR.save(deps.storage, &Addr::unchecked(new_operator))?;
+        }
+    }
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Balance { address } => to_binary(&BalanceResponse {
+            balance: balance_of(deps.storage, Addr::unchecked(address))?,
+        }),
+        QueryMsg::Allowance { owner, spender } => to_binary(&AllowanceResponse {
+            allowance: ALLOWANCES
+                .may_load(deps.storage, (Addr::unchecked(owner), Addr::unchecked(spender)))?
+                .unwrap_or_default(),
+        }),
+        QueryMsg::TotalSupply {} => to_binary(&TOTAL_SUPPLY.load(deps.storage)?),
+        QueryMsg::Operator {} => to_binary(&OPERATOR.load(deps.storage)?),
+        QueryMsg::IsOperator {} => Err(StdError::generic_err("is_operator needs a caller")),
+    }
+}
+
+fn check_onlyoperator(storage: &dyn Storage, sender: &Addr) -> StdResult<()> {
+    if OPERATOR.load(storage)? != *sender {
+        return Err(StdError::generic_err("caller is not the operator"));
+    }
+    Ok(())
+}
+
+fn balance_of(storage: &dyn Storage, account: Addr) -> StdResult<Uint128> {
+    Ok(BALANCES.may_load(storage, account)?.unwrap_or_default())
+}
+
+fn spend_allowance(
+    storage: &mut dyn Storage,
+    owner: Addr,
+    spender: Addr,
+    amount: Uint128,
+) -> StdResult<()> {
+    let key = (owner, spender);
+    let allowance = ALLOWANCES.may_load(storage, key.clone())?.unwrap_or_default();
+    ALLOWANCES.save(storage, key, &allowance.checked_sub(amount)?)?;
+    Ok(())
+}
+
+fn transfer(storage: &mut dyn Storage, from: Addr, to: Addr, amount: Uint128) -> StdResult<()> {
+    let from_balance = balance_of(storage, from.clone())?;
+    BALANCES.save(storage, from, &from_balance.checked_sub(amount)?)?;
+    let to_balance = balance_of(storage, to.clone())?;
+    BALANCES.save(storage, to, &(to_balance + amount))?;
+    Ok(())
+}
+
+fn mint(storage: &mut dyn Storage, to: Addr, amount: Uint128) -> StdResult<()> {
+    let balance = balance_of(storage, to.clone())?;
+    BALANCES.save(storage, to, &(balance + amount))?;
+    let total_supply = TOTAL_SUPPLY.load(storage)?;
+    TOTAL_SUPPLY.save(storage, &(total_supply + amount))?;
+    Ok(())
+}
+
+fn burn(storage: &mut dyn Storage, from: Addr, amount: Uint128) -> StdResult<()> {
+    let balance = balance_of(storage, from.clone())?;
+    BALANCES.save(storage, from, &balance.checked_sub(amount)?)?;
+    let total_supply = TOTAL_SUPPLY.load(storage)?;
+    TOTAL_SUPPLY.save(storage, &total_supply.checked_sub(amount)?)?;
+    Ok(())
+}
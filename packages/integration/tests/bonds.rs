@@ -0,0 +1,232 @@
+use cosmwasm_std::{from_binary, Addr, Uint128};
+
+use integration::suite::{attribute, ether, events, price, Suite};
+use Treasury::msg::{BondPricing, BondQuote, BondQuoteFailure, Cw20HookMsg, ExecuteMsg as TreasuryMsg, Operation, PricePoint, QueryMsg as TreasuryQuery, StateResponse};
+use Treasury::ContractError as TreasuryError;
+
+fn treasury_error_msg(err: anyhow::Error) -> String {
+    match err.downcast::<TreasuryError>().unwrap() {
+        TreasuryError::TreasuryError { msg } => msg,
+        err => panic!("unexpected error {:?}", err),
+    }
+}
+
+// Opens the first epoch with TOMB at the given price
+fn open_epoch_at(tomb_price: Uint128) -> Suite {
+    let mut suite = Suite::new();
+    suite.set_pair_price(tomb_price);
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+    suite
+}
+
+#[test]
+fn buying_bonds_mints_tbond_at_the_discount_rate() {
+    let mut suite = open_epoch_at(price(80));
+    let admin = suite.admin.to_string();
+    let (tomb, tbond) = (suite.tomb.clone(), suite.tbond.clone());
+
+    // half of the 25% discount at 0.80: 1.125 TBOND per TOMB
+    suite.treasury_execute(&admin, &TreasuryMsg::SetDiscountPercent {
+        discount_percent: Uint128::from(5_000u128),
+    }).unwrap();
+
+    let tomb_supply = suite.total_supply(&tomb);
+    let res = suite.buy_bonds("alice", ether(100), price(80)).unwrap();
+
+    let bond_amount = Uint128::from(112_500_000_000_000_000_000u128);
+    assert_eq!(from_binary::<Uint128>(&res.data.clone().unwrap()).unwrap(), bond_amount);
+    assert_eq!(attribute(&res, &suite.treasury, "bond_amount"), Some(bond_amount.to_string()));
+    assert_eq!(attribute(&res, &suite.treasury, "tomb_amount"), Some(ether(100).to_string()));
+
+    assert_eq!(suite.balance(&tomb, "alice"), ether(9_900));
+    assert_eq!(suite.balance(&tbond, "alice"), ether(1_000) + bond_amount);
+    assert_eq!(suite.total_supply(&tbond), ether(1_000) + bond_amount);
+    assert_eq!(suite.total_supply(&tomb), tomb_supply - ether(100));
+}
+
+#[test]
+fn bond_slippage_is_bounded_by_the_target_price() {
+    let mut suite = open_epoch_at(price(90));
+
+    // buyers accept any price up to their target
+    let err = suite.buy_bonds("alice", ether(10), price(89)).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "tomb price moved");
+    suite.buy_bonds("alice", ether(10), price(90)).unwrap();
+    suite.buy_bonds("alice", ether(10), price(95)).unwrap();
+
+    // redeemers accept any price down to their target
+    let mut suite = open_epoch_at(price(120));
+    let err = suite.redeem_bonds("alice", ether(10), price(121)).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "tomb price moved");
+}
+
+#[test]
+fn bond_trades_report_amounts_price_and_rate() {
+    let mut suite = open_epoch_at(price(80));
+    let attr = |key: &str, value: String| (key.to_string(), value);
+
+    let res = suite.buy_bonds("alice", ether(100), price(80)).unwrap();
+    let bought = events(&res, &suite.treasury, "bonds_bought");
+    // one TBOND per TOMB until a discount percent is set
+    assert_eq!(bought, vec![vec![
+        attr("sender", "alice".to_string()),
+        attr("tomb_amount", ether(100).to_string()),
+        attr("bond_amount", ether(100).to_string()),
+        attr("tomb_price", price(80).to_string()),
+        attr("discount_rate", price(100).to_string()),
+    ]]);
+
+    // without bootstrap epochs the first expansion saves seigniorage to pay bonds
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBootstrap {
+        bootstrap_epochs: Uint128::zero(),
+        bootstrap_supply_expansion_percent: Uint128::from(450u128),
+    }).unwrap();
+    suite.set_pair_price(price(120));
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+    let res = suite.redeem_bonds("alice", ether(10), price(120)).unwrap();
+    let redeemed = events(&res, &suite.treasury, "bonds_redeemed");
+    assert_eq!(redeemed.len(), 1);
+    let value = |key: &str| redeemed[0].iter().find(|(k, _)| k == key).map(|(_, v)| v.clone()).unwrap();
+    assert_eq!(value("sender"), "alice");
+    assert_eq!(value("bond_amount"), ether(10).to_string());
+    assert_eq!(value("tomb_price"), price(120).to_string());
+    // the TOMB paid follows the premium rate
+    let rate: u128 = value("premium_rate").parse().unwrap();
+    assert_eq!(value("tomb_amount"), Uint128::from(10 * rate).to_string());
+}
+
+#[test]
+fn bonds_are_traded_in_one_transaction_with_a_cw20_hook() {
+    let mut suite = open_epoch_at(price(80));
+    let (tomb, tbond, treasury) = (suite.tomb.clone(), suite.tbond.clone(), suite.treasury.clone());
+    let buy = Cw20HookMsg::BuyBonds { target_price: price(80) };
+
+    // no allowance, the Treasury burns the TOMB it received
+    let tomb_supply = suite.total_supply(&tomb);
+    let res = suite.send("alice", &tomb, &treasury, ether(100), &buy).unwrap();
+    assert_eq!(events(&res, &treasury, "bonds_bought")[0][0], ("sender".to_string(), "alice".to_string()));
+    assert_eq!(suite.balance(&tomb, "alice"), ether(9_900));
+    assert_eq!(suite.balance(&tbond, "alice"), ether(1_100));
+    assert_eq!(suite.balance(&tomb, treasury.as_str()), Uint128::zero());
+    assert_eq!(suite.total_supply(&tomb), tomb_supply - ether(100));
+
+    // the hook must match the token sent, and passes the same checks as BuyBonds
+    let err = suite.send("alice", &tbond, &treasury, ether(100), &buy).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: bonds are bought with TOMB");
+    let err = suite.send("alice", &tomb, &treasury, ether(100), &Cw20HookMsg::BuyBonds { target_price: price(79) }).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "tomb price moved");
+
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBootstrap {
+        bootstrap_epochs: Uint128::zero(),
+        bootstrap_supply_expansion_percent: Uint128::from(450u128),
+    }).unwrap();
+    suite.set_pair_price(price(120));
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+    let (tomb, tbond, treasury) = (suite.tomb.clone(), suite.tbond.clone(), suite.treasury.clone());
+    let tbond_supply = suite.total_supply(&tbond);
+    let res = suite.send("alice", &tbond, &treasury, ether(10), &Cw20HookMsg::RedeemBonds { target_price: price(120) }).unwrap();
+    let redeemed = events(&res, &treasury, "bonds_redeemed");
+    let tomb_amount: u128 = redeemed[0].iter().find(|(key, _)| key == "tomb_amount").unwrap().1.parse().unwrap();
+    assert_eq!(suite.balance(&tbond, "alice"), ether(990));
+    assert_eq!(suite.balance(&tomb, "alice"), ether(10_000) + Uint128::from(tomb_amount));
+    assert_eq!(suite.total_supply(&tbond), tbond_supply - ether(10));
+}
+
+#[test]
+fn bond_rates_follow_the_selected_pricing_model() {
+    let mut suite = open_epoch_at(price(80));
+    let admin = suite.admin.to_string();
+    let point = |tomb_price: u128, rate: u128| PricePoint { price: price(tomb_price), rate: price(rate) };
+    let discount_rate = |suite: &Suite| -> Uint128 { suite.treasury_query(&TreasuryQuery::GetBondDiscountRate {}) };
+    let pricing: BondPricing = suite.treasury_query(&TreasuryQuery::BondPricing {});
+    assert_eq!(pricing, BondPricing::Linear {});
+
+    // breakpoints must be sorted, discounts below the peg and premiums above it
+    let set_pricing = |discount: Vec<PricePoint>, premium: Vec<PricePoint>| TreasuryMsg::SetBondPricing {
+        pricing: BondPricing::PiecewiseLinear { discount, premium },
+    };
+    let err = suite.treasury_execute(&admin, &set_pricing(vec![point(90, 110), point(50, 150)], vec![point(150, 130)])).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: price points must be strictly ascending");
+    let err = suite.treasury_execute(&admin, &set_pricing(vec![point(110, 110)], vec![point(150, 130)])).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::ValueOutOfRange {}));
+
+    // 0.80 sits three quarters of the way from 0.50 to 0.90
+    suite.treasury_execute(&admin, &set_pricing(
+        vec![point(50, 150), point(90, 110), point(100, 100)],
+        vec![point(110, 100), point(150, 130)],
+    )).unwrap();
+    assert_eq!(discount_rate(&suite), price(120));
+    let res = suite.buy_bonds("alice", ether(100), price(80)).unwrap();
+    assert_eq!(attribute(&res, &suite.treasury, "bond_amount"), Some(ether(120).to_string()));
+
+    // with a full linear discount of 1.25 at 0.80 and a 5% debt ratio
+    suite.treasury_execute(&admin, &TreasuryMsg::SetDiscountPercent { discount_percent: Uint128::from(10_000u128) }).unwrap();
+    suite.treasury_execute(&admin, &TreasuryMsg::SetMaxDebtRatioPercent { max_debt_ratio_percent: Uint128::from(1_000u128) }).unwrap();
+    let tbond = suite.tbond.clone();
+    assert_eq!(suite.total_supply(&tbond), ether(1_120));
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBondPricing {
+        pricing: BondPricing::DebtRatio { taper_from_percent: Uint128::from(1_000u128) },
+    }).unwrap();
+    assert_eq!(discount_rate(&suite), price(125));
+    // tapered from no debt down to none left at the 10% max
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBondPricing {
+        pricing: BondPricing::DebtRatio { taper_from_percent: Uint128::zero() },
+    }).unwrap();
+    let state: StateResponse = suite.treasury_query(&TreasuryQuery::State {});
+    let debt_ratio_percent = ether(1_120).multiply_ratio(10_000u128, state.tomb_circulating_supply);
+    let expected = price(100) + (price(125) - price(100)).multiply_ratio(Uint128::from(1_000u128) - debt_ratio_percent, 1_000u128);
+    assert!(debt_ratio_percent > Uint128::from(500u128) && debt_ratio_percent < Uint128::from(1_000u128));
+    assert_eq!(discount_rate(&suite), expected);
+
+    // premiums follow the curve as well
+    let mut suite = open_epoch_at(price(120));
+    let admin = suite.admin.to_string();
+    suite.treasury_execute(&admin, &set_pricing(vec![point(100, 100)], vec![point(110, 100), point(150, 130)])).unwrap();
+    let premium_rate: Uint128 = suite.treasury_query(&TreasuryQuery::GetBondPremiumRate {});
+    assert_eq!(premium_rate, Uint128::from(1_075_000_000_000_000_000u128));
+}
+
+#[test]
+fn quotes_match_the_trades_or_tell_why_they_fail() {
+    let mut suite = open_epoch_at(price(80));
+    let admin = suite.admin.to_string();
+    let quote_buy = |suite: &Suite, tomb_amount: Uint128| -> BondQuote {
+        suite.treasury_query(&TreasuryQuery::QuoteBuyBonds { tomb_amount })
+    };
+    let unavailable = |reason| BondQuote::Unavailable { reason };
+
+    let quote = quote_buy(&suite, ether(100));
+    assert_eq!(quote, BondQuote::Amount { amount: ether(100), tomb_price: price(80), rate: price(100) });
+    let res = suite.buy_bonds("alice", ether(100), price(80)).unwrap();
+    assert_eq!(attribute(&res, &suite.treasury, "bond_amount"), Some(ether(100).to_string()));
+
+    assert_eq!(quote_buy(&suite, Uint128::zero()), unavailable(BondQuoteFailure::ZeroAmount {}));
+    // 3% of the 20,000 circulating TOMB, 100 already bought
+    assert_eq!(quote_buy(&suite, ether(501)), unavailable(BondQuoteFailure::NotEnoughContractionLeft { tomb_amount_left: ether(500) }));
+    let quote: BondQuote = suite.treasury_query(&TreasuryQuery::QuoteRedeemBonds { bond_amount: ether(100) });
+    assert_eq!(quote, unavailable(BondQuoteFailure::PriceNotEligible { tomb_price: price(80) }));
+
+    // 1,100 TBOND already exceed 10% of the 10,000 TOMB left once alice is excluded
+    suite.treasury_execute(&admin, &TreasuryMsg::SetMaxDebtRatioPercent { max_debt_ratio_percent: Uint128::from(1_000u128) }).unwrap();
+    suite.treasury_execute(&admin, &TreasuryMsg::AddExcludedAddress { address: Addr::unchecked("alice") }).unwrap();
+    assert_eq!(quote_buy(&suite, ether(100)), unavailable(BondQuoteFailure::OverMaxDebtRatio { bond_amount_left: Uint128::zero() }));
+    let err = suite.buy_bonds("bob", ether(100), price(80)).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "over max debt ratio");
+
+    suite.treasury_execute(&admin, &TreasuryMsg::SetGuardian { guardian: Some(suite.keeper.clone()) }).unwrap();
+    let keeper = suite.keeper.to_string();
+    suite.treasury_execute(&keeper, &TreasuryMsg::Pause { operations: vec![Operation::BuyBonds] }).unwrap();
+    assert_eq!(quote_buy(&suite, ether(100)), unavailable(BondQuoteFailure::Paused {}));
+
+    // bootstrap epochs save nothing to pay redemptions with
+    let suite = open_epoch_at(price(120));
+    let quote: BondQuote = suite.treasury_query(&TreasuryQuery::QuoteRedeemBonds { bond_amount: ether(100) });
+    assert_eq!(quote, unavailable(BondQuoteFailure::NotEnoughBudget { tomb_balance: Uint128::zero() }));
+}
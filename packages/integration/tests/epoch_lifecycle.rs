@@ -0,0 +1,603 @@
+use cosmwasm_std::{to_binary, Addr, SubMsg, Uint128, WasmMsg};
+use cw_multi_test::Executor;
+use serde::Serialize;
+
+use integration::suite::{attribute, ether, last_treasury_response, price, Suite, PERIOD};
+use integration::token;
+use BasisAsset::msg::ExecuteMsg as BasisAssetMsg;
+use BondTreasury::msg::{ExecuteMsg as BondTreasuryMsg, QueryMsg as BondTreasuryQuery};
+use IMasonry::msg::ExecuteMsg as MasonryMsg;
+use Masonry::ContractError as MasonryError;
+use Oracle::msg::ExecuteMsg as OracleMsg;
+use Treasury::contract::UPDATE_TOMB_PRICE_REPLY_ID;
+use Treasury::msg::{
+    CatchUpPolicy, CircuitBreaker, ConfigResponse, EpochRecord, ExecuteMsg as TreasuryMsg, KeeperBounty, KeeperBountyResponse, Operation,
+    QueryMsg as TreasuryQuery, SeigniorageRecipient, SeigniorageRecipientResponse, StateResponse,
+};
+use lib::pause::{PauseError, PauseStatus};
+use Treasury::ContractError as TreasuryError;
+use BondTreasuryContract::ContractError as BondTreasuryError;
+
+fn treasury_error(err: anyhow::Error) -> TreasuryError {
+    err.downcast::<TreasuryError>().unwrap()
+}
+
+fn wasm_msg<T: Serialize>(contract: &Addr, msg: &T) -> WasmMsg {
+    WasmMsg::Execute { contract_addr: contract.to_string(), msg: to_binary(msg).unwrap(), funds: vec![] }
+}
+
+// Messages the Treasury dispatches to hand `amount` of seigniorage to the Masonry
+fn masonry_allocation(suite: &Suite, amount: Uint128) -> Vec<SubMsg> {
+    vec![
+        SubMsg::new(wasm_msg(&suite.tomb, &BasisAssetMsg::Mint { recipient: suite.treasury.to_string(), amount })),
+        SubMsg::new(wasm_msg(&suite.tomb, &BasisAssetMsg::Approve { spender: suite.masonry.to_string(), amount: Uint128::zero() })),
+        SubMsg::new(wasm_msg(&suite.tomb, &BasisAssetMsg::Approve { spender: suite.masonry.to_string(), amount })),
+        SubMsg::new(wasm_msg(&suite.masonry, &MasonryMsg::AllocateSeigniorage { amount })),
+    ]
+}
+
+// Messages the Treasury dispatches to put `amount` more TOMB up for sale in the BondTreasury
+fn bond_treasury_top_up(suite: &Suite, amount: Uint128) -> Vec<SubMsg> {
+    vec![
+        SubMsg::new(wasm_msg(&suite.tomb, &BasisAssetMsg::Mint { recipient: suite.bond_treasury.to_string(), amount })),
+        SubMsg::new(wasm_msg(&suite.bond_treasury, &BondTreasuryMsg::TopUp { amount })),
+    ]
+}
+
+// Moves to the next epoch, refreshes the Oracle at the given price and allocates seigniorage
+fn run_epoch(suite: &mut Suite, tomb_price: Uint128) {
+    suite.next_epoch();
+    suite.set_pair_price(tomb_price);
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+}
+
+#[test]
+fn allocation_is_gated_by_epoch() {
+    let mut suite = Suite::new();
+
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+    assert_eq!(suite.epoch(), Uint128::from(1u128));
+
+    // the next epoch only opens one period later
+    suite.next_block();
+    let err = suite.allocate_seigniorage().unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::NotOpenedYet {}));
+
+    suite.next_epoch();
+    suite.allocate_seigniorage().unwrap();
+    assert_eq!(suite.epoch(), Uint128::from(2u128));
+
+    let next_epoch_point: Uint128 = suite.treasury_query(&TreasuryQuery::NextEpochPoint {});
+    assert_eq!(next_epoch_point, Uint128::from(suite.start_time + 2 * PERIOD));
+}
+
+#[test]
+fn allocation_requires_treasury_to_operate_the_protocol() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+
+    // move the Masonry away from the Treasury
+    suite.treasury_execute(&admin, &TreasuryMsg::MasonryProposeOperator {
+        operator: suite.admin.clone(),
+    }).unwrap();
+    suite.masonry_execute(&admin, &MasonryMsg::AcceptOperator {}).unwrap();
+
+    let err = suite.allocate_seigniorage().unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::NeedMorePermission {}));
+}
+
+#[test]
+fn seigniorage_transfers_cannot_be_triggered_from_outside() {
+    let mut suite = Suite::new();
+    let (tomb, admin) = (suite.tomb.clone(), suite.admin.to_string());
+    let tomb_supply = suite.total_supply(&tomb);
+
+    // not even the operator can call them
+    for sender in ["alice", admin.as_str()] {
+        let err = suite.treasury_execute(sender, &TreasuryMsg::SendToMasonry { amount: ether(1_000) }).unwrap_err();
+        assert!(matches!(treasury_error(err), TreasuryError::Unauthorized {}));
+        let err = suite.treasury_execute(sender, &TreasuryMsg::SendToBondTreasury { amount: ether(1_000) }).unwrap_err();
+        assert!(matches!(treasury_error(err), TreasuryError::Unauthorized {}));
+    }
+    assert_eq!(suite.total_supply(&tomb), tomb_supply);
+}
+
+#[test]
+fn contraction_allowance_refills_every_epoch() {
+    let mut suite = Suite::new();
+    let contraction_left = |suite: &Suite| suite.treasury_query::<StateResponse>(&TreasuryQuery::State {}).epoch_supply_contraction_left;
+
+    // at peg: 3% of the 20_000 circulating TOMB
+    suite.update_oracle().unwrap();
+    let res = suite.allocate_seigniorage().unwrap();
+    assert_eq!(contraction_left(&suite), ether(600));
+    assert_eq!(attribute(&res, &suite.treasury, "epoch_supply_contraction_left"), Some(ether(600).to_string()));
+
+    // above the ceiling nothing can be contracted
+    run_epoch(&mut suite, price(102));
+    assert_eq!(contraction_left(&suite), Uint128::zero());
+
+    // the allowance is reset on every epoch, whatever was left:
+    // 3% of the 22_050 TOMB circulating after two 5% bootstrap expansions
+    run_epoch(&mut suite, price(95));
+    let allowance = Uint128::from(661_500_000_000_000_000_000u128);
+    assert_eq!(contraction_left(&suite), allowance);
+    suite.buy_bonds("alice", ether(250), price(100)).unwrap();
+    assert_eq!(contraction_left(&suite), allowance - ether(250));
+
+    suite.next_epoch();
+    suite.update_oracle().unwrap();
+    let res = suite.allocate_seigniorage().unwrap();
+    assert_eq!(attribute(&res, &suite.treasury, "epoch_supply_contraction_left"), Some(contraction_left(&suite).to_string()));
+    assert!(contraction_left(&suite) > allowance - ether(250));
+}
+
+#[test]
+fn seigniorage_bonds_and_staking_across_epochs() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let (tomb, tbond, tshare) = (suite.tomb.clone(), suite.tbond.clone(), suite.tshare.clone());
+
+    // shorten the bootstrap so the price driven policy kicks in on the second epoch
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBootstrap {
+        bootstrap_epochs: Uint128::from(2u128),
+        bootstrap_supply_expansion_percent: Uint128::from(450u128),
+    }).unwrap();
+
+//epoch 1: bootstrap at peg, bob joins the Masonry
+    suite.set_pair_price(price(100));
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+    assert_eq!(suite.epoch(), Uint128::from(1u128));
+    let tomb_price: Uint128 = suite.treasury_query(&TreasuryQuery::GetTombPrice {});
+    assert_eq!(tomb_price, price(100));
+
+    suite.stake("bob", ether(100)).unwrap();
+    assert_eq!(suite.balance(&tshare, "bob"), Uint128::zero());
+    assert_eq!(suite.balance(&tshare, suite.masonry.as_str()), ether(200));
+
+//epoch 2: expansion at 1.20 with outstanding debt
+    run_epoch(&mut suite, price(120));
+    assert_eq!(suite.epoch(), Uint128::from(2u128));
+
+    // circulating supply is 20_900 (genesis pool excluded), expansion capped at the 4.5% tier:
+    // 940.5 TOMB of seigniorage, 35% for the Masonry and 65% saved by the Treasury to pay bonds
+    let reserve: Uint128 = suite.treasury_query(&TreasuryQuery::GetReserve {});
+    assert_eq!(reserve, Uint128::from(611_325_000_000_000_000_000u128));
+    assert_eq!(suite.balance(&tomb, suite.treasury.as_str()), reserve);
+
+    // alice redeems half her bonds at a 1.14 premium rate
+    let rate: Uint128 = suite.treasury_query(&TreasuryQuery::GetBondPremiumRate {});
+    assert_eq!(rate, price(114));
+    suite.redeem_bonds("alice", ether(500), price(100)).unwrap();
+    assert_eq!(suite.balance(&tbond, "alice"), ether(500));
+    assert_eq!(suite.total_supply(&tbond), ether(500));
+    assert_eq!(suite.balance(&tomb, "alice"), ether(10_570));
+    let left = Uint128::from(41_325_000_000_000_000_000u128);
+    assert_eq!(suite.balance(&tomb, suite.treasury.as_str()), left);
+    let reserve: Uint128 = suite.treasury_query(&TreasuryQuery::GetReserve {});
+    assert_eq!(reserve, left);
+
+    // the treasury cannot pay out more than it holds
+    let err = suite.redeem_bonds("alice", ether(500), price(100)).unwrap_err();
+    match treasury_error(err) {
+        TreasuryError::TreasuryError { msg } => assert_eq!(msg, "Treasury: treasury has no more budget"),
+        err => panic!("unexpected error {:?}", err),
+    }
+
+//epoch 3: TOMB under peg
+    run_epoch(&mut suite, price(90));
+    assert_eq!(suite.epoch(), Uint128::from(3u128));
+    let reserve: Uint128 = suite.treasury_query(&TreasuryQuery::GetReserve {});
+    assert_eq!(reserve, left);
+
+    let err = suite.redeem_bonds("alice", ether(10), price(100)).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::TreasuryError { .. }));
+
+    // under peg the epoch opens with 3% of the 21_840.5 circulating TOMB for sale as bonds
+    let burnable: Uint128 = suite.treasury_query(&TreasuryQuery::GetBurnableTombLeft {});
+    assert_eq!(burnable, Uint128::from(655_215_000_000_000_000_000u128));
+    // no discount configured, one TBOND per TOMB
+    suite.buy_bonds("alice", ether(100), price(100)).unwrap();
+    assert_eq!(suite.balance(&tomb, "alice"), ether(10_470));
+    assert_eq!(suite.balance(&tbond, "alice"), ether(600));
+    assert_eq!(suite.total_supply(&tbond), ether(600));
+    let burnable: Uint128 = suite.treasury_query(&TreasuryQuery::GetBurnableTombLeft {});
+    assert_eq!(burnable, Uint128::from(555_215_000_000_000_000_000u128));
+
+    let err = suite.buy_bonds("alice", ether(600), price(100)).unwrap_err();
+    match treasury_error(err) {
+        TreasuryError::TreasuryError { msg } => assert_eq!(msg, "not enough bond left to purchase"),
+        err => panic!("unexpected error {:?}", err),
+    }
+
+    // shares stay locked for 3 epochs
+    let err = suite.withdraw("bob", ether(100)).unwrap_err();
+    assert!(matches!(err.downcast::<MasonryError>().unwrap(), MasonryError::StillInLockup {}));
+
+//epochs 4 to 11: back at peg
+    run_epoch(&mut suite, price(100));
+    // and rewards for 10, withdrawing would claim them
+    let err = suite.withdraw("bob", ether(40)).unwrap_err();
+    assert!(matches!(err.downcast::<MasonryError>().unwrap(), MasonryError::StillInLockup {}));
+    for _ in 5..=11 {
+        run_epoch(&mut suite, price(100));
+    }
+    assert_eq!(suite.epoch(), Uint128::from(11u128));
+
+    // withdrawing claims the half of the epoch 2 Masonry allocation bob earned next to carol
+    suite.withdraw("bob", ether(40)).unwrap();
+    assert_eq!(suite.balance(&tshare, "bob"), ether(40));
+    assert_eq!(suite.balance(&tomb, "bob"), Uint128::from(10_164_587_500_000_000_000_000u128));
+    assert_eq!(suite.balance(&tshare, suite.masonry.as_str()), ether(160));
+
+    // supply only moved through the bootstrap and expansion mints, the bond purchase and
+    // the TOMB put up for sale in the BondTreasury
+    let for_sale = suite.balance(&tomb, suite.bond_treasury.as_str());
+    assert_eq!(for_sale, Uint128::from(1_089_958_750_000_000_000_000u128));
+    assert_eq!(suite.total_supply(&tomb), Uint128::from(26_740_500_000_000_000_000_000u128) + for_sale);
+}
+
+#[test]
+fn allocation_dispatches_every_step_of_the_epoch() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBootstrap {
+        bootstrap_epochs: Uint128::from(2u128),
+        bootstrap_supply_expansion_percent: Uint128::from(450u128),
+    }).unwrap();
+    let update_price = SubMsg::reply_on_error(wasm_msg(&suite.oracle, &OracleMsg::Update {}), UPDATE_TOMB_PRICE_REPLY_ID);
+
+    // bootstrap: 5% of the 20_000 circulating TOMB up for sale as bonds and 4.5% for the Masonry
+    suite.update_oracle().unwrap();
+    let res = suite.allocate_seigniorage().unwrap();
+    let mut expected = vec![update_price.clone()];
+    expected.extend(bond_treasury_top_up(&suite, ether(1_000)));
+    expected.extend(masonry_allocation(&suite, ether(900)));
+    assert_eq!(last_treasury_response().unwrap().messages, expected);
+    // the Oracle was already updated this period, the Treasury goes on without it
+    assert!(res.events.iter().flat_map(|event| event.attributes.iter())
+        .any(|attr| attr.key == "action" && attr.value == "Oracle Update skipped"));
+    assert_eq!(suite.balance(&suite.tomb, suite.masonry.as_str()), ether(900));
+
+    // expansion: the sale is topped up to 5% of the 20_900 TOMB, 35% of the seigniorage
+    // for the Masonry and the rest minted to the Treasury to pay bonds
+    run_epoch(&mut suite, price(120));
+    let mut expected = vec![update_price.clone()];
+    expected.extend(bond_treasury_top_up(&suite, ether(45)));
+    expected.extend(masonry_allocation(&suite, Uint128::from(329_175_000_000_000_000_000u128)));
+    expected.push(SubMsg::new(wasm_msg(&suite.tomb, &BasisAssetMsg::Mint {
+        recipient: suite.treasury.to_string(),
+        amount: Uint128::from(611_325_000_000_000_000_000u128),
+    })));
+    assert_eq!(last_treasury_response().unwrap().messages, expected);
+
+    // under peg only the sale keeps up with the 21_229.175 TOMB not saved to pay bonds
+    run_epoch(&mut suite, price(90));
+    let mut expected = vec![update_price];
+    expected.extend(bond_treasury_top_up(&suite, Uint128::from(16_458_750_000_000_000_000u128)));
+    assert_eq!(last_treasury_response().unwrap().messages, expected);
+}
+
+#[test]
+fn keepers_are_paid_for_opening_epochs() {
+    let mut suite = Suite::new();
+    let (tomb, admin) = (suite.tomb.clone(), suite.admin.to_string());
+    let keeper = suite.keeper.to_string();
+    let set = |bounty: Option<KeeperBounty>| TreasuryMsg::SetKeeperBounty { bounty };
+
+    let err = suite.treasury_execute("alice", &set(Some(KeeperBounty::Fixed { amount: ether(1) }))).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::Unauthorized {}));
+    let err = suite.treasury_execute(&admin, &set(Some(KeeperBounty::Fixed { amount: ether(1_001) }))).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::ValueOutOfRange {}));
+    let err = suite.treasury_execute(&admin, &set(Some(KeeperBounty::Percent {
+        percent: Uint128::from(501u128),
+        cap: ether(10),
+    }))).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::ValueOutOfRange {}));
+
+    // 1% of the 1_000 TOMB bootstrap expansion, capped at 5 TOMB
+    let bounty = KeeperBounty::Percent { percent: Uint128::from(100u128), cap: ether(5) };
+    suite.treasury_execute(&admin, &set(Some(bounty.clone()))).unwrap();
+    suite.update_oracle().unwrap();
+    let quote: KeeperBountyResponse = suite.treasury_query(&TreasuryQuery::KeeperBounty {});
+    assert_eq!(quote, KeeperBountyResponse { bounty: Some(bounty), next_bounty: ether(5) });
+    let res = suite.allocate_seigniorage().unwrap();
+    assert_eq!(suite.balance(&tomb, &keeper), ether(5));
+    assert_eq!(attribute(&res, &suite.treasury, "keeper_bounty"), Some(ether(5).to_string()));
+
+    // below the ceiling no seigniorage is minted, not even a fixed bounty
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBootstrap {
+        bootstrap_epochs: Uint128::zero(),
+        bootstrap_supply_expansion_percent: Uint128::from(450u128),
+    }).unwrap();
+    suite.treasury_execute(&admin, &set(Some(KeeperBounty::Fixed { amount: ether(2) }))).unwrap();
+    let tomb_supply = suite.total_supply(&tomb);
+    suite.next_epoch();
+    suite.set_pair_price(price(90));
+    suite.update_oracle().unwrap();
+    let quote: KeeperBountyResponse = suite.treasury_query(&TreasuryQuery::KeeperBounty {});
+    assert_eq!(quote.next_bounty, Uint128::zero());
+    let res = suite.allocate_seigniorage().unwrap();
+    assert_eq!(attribute(&res, &suite.treasury, "keeper_bounty"), None);
+    assert_eq!(suite.balance(&tomb, &keeper), ether(5));
+    let history: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: Some(1), limit: None });
+    assert_eq!(suite.total_supply(&tomb), tomb_supply + history[0].to_bond_treasury);
+
+    // and paid in full out of an expansion
+    run_epoch(&mut suite, price(120));
+    assert_eq!(suite.balance(&tomb, &keeper), ether(7));
+
+    suite.treasury_execute(&admin, &set(None)).unwrap();
+    let quote: KeeperBountyResponse = suite.treasury_query(&TreasuryQuery::KeeperBounty {});
+    assert_eq!(quote, KeeperBountyResponse { bounty: None, next_bounty: Uint128::zero() });
+    run_epoch(&mut suite, price(120));
+    assert_eq!(suite.balance(&tomb, &keeper), ether(7));
+}
+
+#[test]
+fn missed_epochs_follow_the_catch_up_policy() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let set = |policy: Option<CatchUpPolicy>| TreasuryMsg::SetCatchUpPolicy { policy };
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+
+    // without a policy every missed epoch is allocated, all in the same block
+    for _ in 0..3 {
+        suite.next_epoch();
+    }
+    for _ in 0..3 {
+        suite.allocate_seigniorage().unwrap();
+    }
+    assert_eq!(suite.epoch(), Uint128::from(4u128));
+    let err = suite.allocate_seigniorage().unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::NotOpenedYet {}));
+
+    let err = suite.treasury_execute("alice", &set(Some(CatchUpPolicy::Skip {}))).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::Unauthorized {}));
+    let err = suite.treasury_execute(&admin, &set(Some(CatchUpPolicy::CapPerBlock { max_epochs: 0 }))).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::ValueOutOfRange {}));
+
+    // capped: one epoch per block
+    suite.treasury_execute(&admin, &set(Some(CatchUpPolicy::CapPerBlock { max_epochs: 1 }))).unwrap();
+    let policy: Option<CatchUpPolicy> = suite.treasury_query(&TreasuryQuery::CatchUpPolicy {});
+    assert_eq!(policy, Some(CatchUpPolicy::CapPerBlock { max_epochs: 1 }));
+    suite.next_epoch();
+    suite.next_epoch();
+    suite.allocate_seigniorage().unwrap();
+    let err = suite.allocate_seigniorage().unwrap_err();
+    match treasury_error(err) {
+        TreasuryError::TreasuryError { msg } => assert_eq!(msg, "Treasury: too many epochs allocated in this block"),
+        err => panic!("unexpected error {:?}", err),
+    }
+    suite.next_block();
+    suite.allocate_seigniorage().unwrap();
+    assert_eq!(suite.epoch(), Uint128::from(6u128));
+
+    // skip: straight to the current epoch, epochs 7 and 8 get nothing
+    suite.treasury_execute(&admin, &set(Some(CatchUpPolicy::Skip {}))).unwrap();
+    for _ in 0..3 {
+        suite.next_epoch();
+    }
+    let simulation: EpochRecord = suite.treasury_query(&TreasuryQuery::SimulateAllocation {});
+    assert_eq!(simulation.epoch, 9);
+    let res = suite.allocate_seigniorage().unwrap();
+    assert_eq!(suite.epoch(), Uint128::from(9u128));
+    let skipped = res.events.iter().find(|event| event.ty == "wasm-skip_epochs").unwrap();
+    let range: Vec<(&str, &str)> = skipped.attributes.iter()
+        .filter(|attr| attr.key == "from" || attr.key == "to")
+        .map(|attr| (attr.key.as_str(), attr.value.as_str()))
+        .collect();
+    assert_eq!(range, vec![("from", "7"), ("to", "8")]);
+    let err = suite.allocate_seigniorage().unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::NotOpenedYet {}));
+    let next_epoch_point: Uint128 = suite.treasury_query(&TreasuryQuery::NextEpochPoint {});
+    assert_eq!(next_epoch_point, Uint128::from(suite.start_time + 9 * PERIOD));
+}
+
+#[test]
+fn period_changes_apply_from_the_next_epoch_boundary() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let next_epoch_point = |suite: &Suite| suite.treasury_query::<Uint128>(&TreasuryQuery::NextEpochPoint {});
+    let set = |hours: u64| TreasuryMsg::SetPeriod { period: Uint128::from(hours * 3_600) };
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+
+    let err = suite.treasury_execute("alice", &set(2)).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::Unauthorized {}));
+    for hours in [0, 49] {
+        let err = suite.treasury_execute(&admin, &set(hours)).unwrap_err();
+        assert!(matches!(treasury_error(err), TreasuryError::ValueOutOfRange {}));
+    }
+
+    // the running epoch keeps its end
+    suite.treasury_execute(&admin, &set(2)).unwrap();
+    let config: ConfigResponse = suite.treasury_query(&TreasuryQuery::Config {});
+    assert_eq!(config.period, Uint128::from(7_200u128));
+    let first_boundary = suite.start_time + PERIOD;
+    assert_eq!(next_epoch_point(&suite), Uint128::from(first_boundary));
+
+    suite.next_epoch();
+    suite.allocate_seigniorage().unwrap();
+    assert_eq!(next_epoch_point(&suite), Uint128::from(first_boundary + 7_200));
+
+    // changing it again mid-epoch does not move the boundary either
+    suite.treasury_execute(&admin, &set(12)).unwrap();
+    assert_eq!(next_epoch_point(&suite), Uint128::from(first_boundary + 7_200));
+    suite.next_epoch();
+    suite.allocate_seigniorage().unwrap();
+    assert_eq!(suite.epoch(), Uint128::from(3u128));
+    assert_eq!(next_epoch_point(&suite), Uint128::from(first_boundary + 7_200 + 43_200));
+}
+
+#[test]
+fn extreme_price_moves_trip_the_circuit_breaker() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    // a single bootstrap epoch, as in the epoch history
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBootstrap {
+        bootstrap_epochs: Uint128::from(2u128),
+        bootstrap_supply_expansion_percent: Uint128::from(450u128),
+    }).unwrap();
+    let breaker = CircuitBreaker {
+        max_price_move: Uint128::from(1_000u128),
+        tripped_expansion_percent: Uint128::from(100u128),
+        pause_bonds: true,
+        reanchor_after: Uint128::from(3u128),
+    };
+    let err = suite.treasury_execute(&admin, &TreasuryMsg::SetCircuitBreaker {
+        breaker: Some(CircuitBreaker { max_price_move: Uint128::from(50u128), ..breaker.clone() }),
+    }).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::ValueOutOfRange {}));
+    let err = suite.treasury_execute(&admin, &TreasuryMsg::SetCircuitBreaker {
+        breaker: Some(CircuitBreaker { reanchor_after: Uint128::zero(), ..breaker.clone() }),
+    }).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::ValueOutOfRange {}));
+    suite.treasury_execute(&admin, &TreasuryMsg::SetCircuitBreaker { breaker: Some(breaker.clone()) }).unwrap();
+    let stored: Option<CircuitBreaker> = suite.treasury_query(&TreasuryQuery::CircuitBreaker {});
+    assert_eq!(stored, Some(breaker));
+
+    // nothing to compare the first TWAP with
+    suite.update_oracle().unwrap();
+    let res = suite.allocate_seigniorage().unwrap();
+    assert!(!res.events.iter().any(|event| event.ty == "wasm-circuit_breaker"));
+
+    // a 20% jump caps the expansion at 1% instead of the 4.5% of the first tier
+    suite.next_epoch();
+    suite.set_pair_price(price(120));
+    suite.update_oracle().unwrap();
+    let simulation: EpochRecord = suite.treasury_query(&TreasuryQuery::SimulateAllocation {});
+    assert!(simulation.circuit_breaker_tripped);
+    let res = suite.allocate_seigniorage().unwrap();
+    let event = res.events.iter().find(|event| event.ty == "wasm-circuit_breaker").unwrap();
+    let values: Vec<(&str, &str)> = event.attributes.iter()
+        .filter(|attr| attr.key != "_contract_addr")
+        .map(|attr| (attr.key.as_str(), attr.value.as_str()))
+        .collect();
+    assert_eq!(values, vec![
+        ("previous_price", "1000000000000000000"),
+        ("price", "1200000000000000000"),
+        ("paused", "bonds"),
+    ]);
+    let history: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: Some(1), limit: None });
+    assert_eq!(history[0], EpochRecord { expansion_percent: Uint128::from(100u128), ..simulation });
+    assert_eq!(history[0].to_masonry, Uint128::from(73_150_000_000_000_000_000u128));
+    assert_eq!(history[0].to_reserve, Uint128::from(135_850_000_000_000_000_000u128));
+
+    // bonds stay paused until the guardian resumes them
+    let status: PauseStatus<Operation> = suite.treasury_query(&TreasuryQuery::PauseStatus {});
+    assert_eq!(status.paused, vec![Operation::BuyBonds, Operation::RedeemBonds]);
+    let err = suite.redeem_bonds("bob", ether(10), price(120)).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::Pause(PauseError::Paused {})));
+
+    // holding the manipulated price does not make it the reference
+    run_epoch(&mut suite, price(120));
+    let history: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: Some(2), limit: None });
+    assert!(history[0].circuit_breaker_tripped);
+    let state: StateResponse = suite.treasury_query(&TreasuryQuery::State {});
+    assert_eq!(state.previous_epoch_tomb_price, price(100));
+
+    // a price back within range of the trusted one does not trip it
+    run_epoch(&mut suite, price(105));
+    let history: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: Some(3), limit: None });
+    assert!(!history[0].circuit_breaker_tripped);
+    let state: StateResponse = suite.treasury_query(&TreasuryQuery::State {});
+    assert_eq!(state.previous_epoch_tomb_price, price(105));
+
+    // a lasting move becomes the reference after three tripped allocations in a row
+    run_epoch(&mut suite, price(130));
+    run_epoch(&mut suite, price(130));
+    suite.next_epoch();
+    suite.set_pair_price(price(130));
+    suite.update_oracle().unwrap();
+    let res = suite.allocate_seigniorage().unwrap();
+    let event = res.events.iter().find(|event| event.ty == "wasm-circuit_breaker").unwrap();
+    assert!(event.attributes.iter().any(|attr| attr.key == "reanchored" && attr.value == "3"));
+    let history: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: Some(4), limit: None });
+    assert!(history.iter().all(|record| record.circuit_breaker_tripped));
+    let state: StateResponse = suite.treasury_query(&TreasuryQuery::State {});
+    assert_eq!(state.previous_epoch_tomb_price, price(130));
+    let status: PauseStatus<Operation> = suite.treasury_query(&TreasuryQuery::PauseStatus {});
+    assert!(status.paused.is_empty());
+
+    // and the allocations expand at the tier rate again
+    run_epoch(&mut suite, price(130));
+    let history: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: Some(7), limit: None });
+    assert!(!history[0].circuit_breaker_tripped);
+    assert_eq!(history[0].expansion_percent, Uint128::from(450u128));
+}
+
+#[test]
+fn seigniorage_recipients_are_paid_before_the_masonry() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let tomb = suite.tomb.clone();
+    let recipient = |name: &str, share: u128| SeigniorageRecipient {
+        name: name.to_string(),
+        address: Addr::unchecked(name),
+        share: Uint128::from(share),
+    };
+    let set_recipients = |recipients: Vec<SeigniorageRecipient>| TreasuryMsg::SetSeigniorageRecipients { recipients };
+
+    // at most 40% of the seigniorage saved for the Masonry
+    let err = suite.treasury_execute(&admin, &set_recipients(vec![recipient("insurance", 1_500), recipient("buyback", 2_501)])).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::ValueOutOfRange {}));
+    let err = suite.treasury_execute(&admin, &set_recipients(vec![recipient("insurance", 0)])).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::ValueOutOfRange {}));
+    let err = suite.treasury_execute(&admin, &set_recipients(vec![recipient("insurance", 100), recipient("insurance", 100)])).unwrap_err();
+    assert!(matches!(treasury_error(err), TreasuryError::TreasuryError { msg } if msg == "Treasury: recipient names must be unique and not empty"));
+    suite.treasury_execute(&admin, &set_recipients(vec![recipient("insurance", 1_000), recipient("buyback", 2_500)])).unwrap();
+
+    // bootstrap epoch: 5% of 20_000 TOMB, 10% and 25% of it to the recipients
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+    assert_eq!(suite.balance(&tomb, "insurance"), ether(100));
+    assert_eq!(suite.balance(&tomb, "buyback"), ether(250));
+    let history: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: None, limit: None });
+    assert_eq!(history[0].to_masonry, ether(650));
+
+    // amounts paid are kept by name when the list changes
+    run_epoch(&mut suite, price(100));
+    suite.treasury_execute(&admin, &set_recipients(vec![recipient("insurance", 500)])).unwrap();
+    let recipients: Vec<SeigniorageRecipientResponse> = suite.treasury_query(&TreasuryQuery::SeigniorageRecipients {});
+    assert_eq!(recipients, vec![SeigniorageRecipientResponse {
+        name: "insurance".to_string(),
+        address: Addr::unchecked("insurance"),
+        share: Uint128::from(500u128),
+        // 10% of 1_000 then of 1_050 TOMB
+        paid: ether(205),
+    }]);
+}
+
+#[test]
+fn bond_treasury_only_sells_what_the_treasury_topped_up() {
+    let mut suite = Suite::new();
+    let (tomb, bond_treasury) = (suite.tomb.clone(), suite.bond_treasury.clone());
+    let tomb_for_sale = |suite: &Suite| -> Uint128 { suite.query(&suite.bond_treasury, &BondTreasuryQuery::TombForSale {}) };
+    let err = suite.app.execute_contract(Addr::unchecked("alice"), bond_treasury.clone(),
+        &BondTreasuryMsg::TopUp { amount: ether(1) }, &[]).unwrap_err();
+    assert!(matches!(err.downcast::<BondTreasuryError>().unwrap(), BondTreasuryError::Unauthorized {}));
+
+    // the first allocation puts 5% of the 20_000 circulating TOMB up for sale
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+    assert_eq!(tomb_for_sale(&suite), ether(1_000));
+
+    // TOMB sent by anyone else is not for sale
+    suite.app.execute_contract(Addr::unchecked("alice"), tomb.clone(), &token::ExecuteMsg::Transfer {
+        recipient: bond_treasury.to_string(),
+        amount: ether(1),
+    }, &[]).unwrap();
+
+    // an expansion grows the sale to 5% of the 20_999 TOMB left in circulation
+    run_epoch(&mut suite, price(120));
+    let history: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: Some(1), limit: None });
+    let to_bond_treasury = Uint128::from(49_950_000_000_000_000_000u128);
+    assert_eq!(history[0].to_bond_treasury, to_bond_treasury);
+    assert_eq!(tomb_for_sale(&suite), ether(1_000) + to_bond_treasury);
+    assert_eq!(suite.balance(&tomb, bond_treasury.as_str()), ether(1_001) + to_bond_treasury);
+}
@@ -0,0 +1,316 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_multi_test::Executor;
+
+use integration::suite::{attribute, ether, events, price, Suite, PERIOD};
+use integration::token;
+use BasisAsset::msg::InitialBalance;
+use lib::pause::{PauseError, PauseStatus};
+use IMasonry::msg::{ExecuteMsg as MasonryMsg, Operation as MasonryOperation, QueryMsg as MasonryQuery};
+use Masonry::ContractError as MasonryError;
+use Treasury::msg::{
+    ConfigResponse, ExecuteMsg as TreasuryMsg, KeeperBounty, Operation, QueryMsg as TreasuryQuery, QueuedTransaction, SeigniorageRecipient,
+};
+use Treasury::ContractError as TreasuryError;
+
+fn treasury_error_msg(err: anyhow::Error) -> String {
+    match err.downcast::<TreasuryError>().unwrap() {
+        TreasuryError::TreasuryError { msg } => msg,
+        err => panic!("unexpected error {:?}", err),
+    }
+}
+
+fn queued_transactions(suite: &Suite) -> Vec<QueuedTransaction> {
+    suite.treasury_query(&TreasuryQuery::QueuedTransactions { start_after: None, limit: None })
+}
+
+#[test]
+fn setters_wait_for_the_timelock() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let delay = Uint128::from(PERIOD);
+    let set_debt_ratio = |percent: u128| TreasuryMsg::SetMaxDebtRatioPercent {
+        max_debt_ratio_percent: Uint128::from(percent),
+    };
+    let queue = |msg: TreasuryMsg| TreasuryMsg::QueueTransaction { msg: Box::new(msg) };
+
+    // setters apply immediately until the timelock is enabled
+    let err = suite.treasury_execute(&admin, &queue(set_debt_ratio(4_000))).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: timelock is not enabled");
+    let err = suite.treasury_execute(&admin, &TreasuryMsg::SetTimelockDelay { delay: Some(Uint128::from(60u128)) }).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::ValueOutOfRange {}));
+    suite.treasury_execute(&admin, &TreasuryMsg::SetTimelockDelay { delay: Some(delay) }).unwrap();
+    let timelock_delay: Option<Uint128> = suite.treasury_query(&TreasuryQuery::TimelockDelay {});
+    assert_eq!(timelock_delay, Some(delay));
+
+    let err = suite.treasury_execute(&admin, &set_debt_ratio(4_000)).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: setters must be queued while the timelock is enabled");
+    let err = suite.treasury_execute(&admin, &queue(TreasuryMsg::AllocateSeigniorage {})).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: only setters can be queued");
+
+    // Initialize cannot reset the parameters behind the timelock's back
+    let initialize = TreasuryMsg::Initialize {
+        tomb: suite.tomb.clone(),
+        tbond: suite.tbond.clone(),
+        tshare: suite.tshare.clone(),
+        tomb_oracle: suite.oracle.clone(),
+        masonry: suite.masonry.clone(),
+        genesis_pool: suite.genesis_pool.clone(),
+        bond_treasury: suite.bond_treasury.clone(),
+        start_time: Uint128::from(suite.start_time),
+        period: Uint128::from(PERIOD),
+    };
+    let err = suite.treasury_execute(&admin, &initialize).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::AlreadyInitialized {}));
+    let err = suite.treasury_execute(&admin, &queue(initialize)).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: only setters can be queued");
+    let err = suite.treasury_execute("alice", &queue(set_debt_ratio(4_000))).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::Unauthorized {}));
+
+    let treasury = suite.treasury.clone();
+    let res = suite.treasury_execute(&admin, &queue(set_debt_ratio(4_000))).unwrap();
+    assert_eq!(attribute(&res, &treasury, "id"), Some("0".to_string()));
+    suite.treasury_execute(&admin, &queue(TreasuryMsg::SetSeigniorageRecipients {
+        recipients: vec![SeigniorageRecipient { name: "dao_fund".to_string(), address: Addr::unchecked("dao"), share: Uint128::from(1_000u128) }],
+    })).unwrap();
+    let eta = Uint128::from(suite.app.block_info().time.seconds()) + delay;
+    let queued = queued_transactions(&suite);
+    assert_eq!(queued.len(), 2);
+    assert_eq!(queued[0], QueuedTransaction { id: 0, msg: set_debt_ratio(4_000), eta });
+    assert_eq!(queued[1].id, 1);
+
+    // holders get the whole delay to react
+    let err = suite.treasury_execute(&admin, &TreasuryMsg::ExecuteTransaction { id: 0 }).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: transaction is still timelocked");
+    suite.treasury_execute(&admin, &TreasuryMsg::CancelTransaction { id: 1 }).unwrap();
+    let err = suite.treasury_execute(&admin, &TreasuryMsg::CancelTransaction { id: 1 }).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: unknown transaction");
+
+    suite.next_epoch();
+    let err = suite.treasury_execute("alice", &TreasuryMsg::ExecuteTransaction { id: 0 }).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::Unauthorized {}));
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::ExecuteTransaction { id: 0 }).unwrap();
+    assert_eq!(attribute(&res, &treasury, "action"), Some("set max debt ratio percent".to_string()));
+    assert_eq!(attribute(&res, &treasury, "executed_transaction"), Some("0".to_string()));
+    let config: ConfigResponse = suite.treasury_query(&TreasuryQuery::Config {});
+    assert_eq!(config.max_debt_ratio_percent, Uint128::from(4_000u128));
+    assert!(config.seigniorage_recipients.is_empty());
+    assert!(queued_transactions(&suite).is_empty());
+
+    // a change left queued past the grace period expires
+    suite.treasury_execute(&admin, &queue(set_debt_ratio(5_000))).unwrap();
+    for _ in 0..58 {
+        suite.next_epoch();
+    }
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::ExecuteTransaction { id: 2 }).unwrap();
+    assert_eq!(attribute(&res, &treasury, "action"), Some("expire transaction".to_string()));
+    assert!(queued_transactions(&suite).is_empty());
+    let config: ConfigResponse = suite.treasury_query(&TreasuryQuery::Config {});
+    assert_eq!(config.max_debt_ratio_percent, Uint128::from(4_000u128));
+    let err = suite.treasury_execute(&admin, &TreasuryMsg::ExecuteTransaction { id: 2 }).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: unknown transaction");
+
+    // turning the timelock off is itself timelocked
+    let err = suite.treasury_execute(&admin, &TreasuryMsg::SetTimelockDelay { delay: None }).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: setters must be queued while the timelock is enabled");
+    suite.treasury_execute(&admin, &queue(TreasuryMsg::SetTimelockDelay { delay: None })).unwrap();
+    suite.next_epoch();
+    suite.treasury_execute(&admin, &TreasuryMsg::ExecuteTransaction { id: 3 }).unwrap();
+    suite.treasury_execute(&admin, &set_debt_ratio(5_000)).unwrap();
+}
+
+#[test]
+fn handovers_and_recoveries_wait_for_the_timelock() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let alice = Addr::unchecked("alice");
+    let tomb = suite.tomb.clone();
+    let queue = |msg: TreasuryMsg| TreasuryMsg::QueueTransaction { msg: Box::new(msg) };
+    suite.treasury_execute(&admin, &TreasuryMsg::SetTimelockDelay { delay: Some(Uint128::from(PERIOD)) }).unwrap();
+
+    let timelocked = vec![
+        TreasuryMsg::ProposeOperator { operator: alice.clone() },
+        TreasuryMsg::GovernanceRecoverUnsupported { token: tomb.clone(), amount: ether(1), to: alice.clone() },
+        TreasuryMsg::MasonryProposeOperator { operator: alice.clone() },
+        TreasuryMsg::MasonryAcceptOperator {},
+        TreasuryMsg::MasonryGovernanceRecoverUnsupported { token: tomb.clone(), amount: ether(1), to: alice.clone() },
+    ];
+    for msg in timelocked {
+        let err = suite.treasury_execute(&admin, &msg).unwrap_err();
+        assert_eq!(treasury_error_msg(err), "Treasury: setters must be queued while the timelock is enabled");
+    }
+
+    // a handover only starts once the delay has passed
+    suite.treasury_execute(&admin, &queue(TreasuryMsg::ProposeOperator { operator: alice.clone() })).unwrap();
+    let pending: Option<Addr> = suite.treasury_query(&TreasuryQuery::PendingOperator {});
+    assert_eq!(pending, None);
+    suite.next_epoch();
+    suite.treasury_execute(&admin, &TreasuryMsg::ExecuteTransaction { id: 0 }).unwrap();
+    let pending: Option<Addr> = suite.treasury_query(&TreasuryQuery::PendingOperator {});
+    assert_eq!(pending, Some(alice.clone()));
+    suite.treasury_execute("alice", &TreasuryMsg::AcceptOperator {}).unwrap();
+    let config: ConfigResponse = suite.treasury_query(&TreasuryQuery::Config {});
+    assert_eq!(config.operator, Some(alice));
+}
+
+#[test]
+fn guardian_pauses_the_treasury_and_the_masonry() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let guardian = Addr::unchecked("guardian");
+    let paused = |err: anyhow::Error| matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::Pause(PauseError::Paused {}));
+    suite.set_pair_price(price(80));
+    suite.update_oracle().unwrap();
+
+    let pause = TreasuryMsg::Pause { operations: vec![Operation::BuyBonds, Operation::AllocateSeigniorage] };
+    let err = suite.treasury_execute(guardian.as_str(), &pause).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::Pause(PauseError::Unauthorized {})));
+    suite.treasury_execute(&admin, &TreasuryMsg::SetGuardian { guardian: Some(guardian.clone()) }).unwrap();
+    suite.treasury_execute(guardian.as_str(), &pause).unwrap();
+    let status: PauseStatus<Operation> = suite.treasury_query(&TreasuryQuery::PauseStatus {});
+    assert_eq!(status, PauseStatus {
+        guardian: Some(guardian.clone()),
+        paused: vec![Operation::BuyBonds, Operation::AllocateSeigniorage],
+    });
+
+    assert!(paused(suite.allocate_seigniorage().unwrap_err()));
+    assert!(paused(suite.buy_bonds("alice", ether(100), price(80)).unwrap_err()));
+    suite.treasury_execute(guardian.as_str(), &TreasuryMsg::Unpause { operations: vec![Operation::AllocateSeigniorage] }).unwrap();
+    suite.allocate_seigniorage().unwrap();
+    assert!(paused(suite.buy_bonds("alice", ether(100), price(80)).unwrap_err()));
+
+    // the Treasury appoints the guardian of the Masonry it operates
+    suite.treasury_execute(&admin, &TreasuryMsg::MasonrySetGuardian { guardian: Some(guardian.clone()) }).unwrap();
+    suite.treasury_execute(&admin, &TreasuryMsg::MasonrySetLockup {
+        withdraw_lockup_epochs: Uint128::zero(),
+        reward_lockup_epochs: Uint128::zero(),
+    }).unwrap();
+    suite.masonry_execute(guardian.as_str(), &MasonryMsg::Pause {
+        operations: vec![MasonryOperation::Stake, MasonryOperation::Withdraw, MasonryOperation::ClaimReward],
+    }).unwrap();
+    let masonry = suite.masonry.clone();
+    let status: PauseStatus<MasonryOperation> = suite.query(&masonry, &MasonryQuery::PauseStatus {});
+    assert_eq!(status.paused.len(), 3);
+
+    let masonry_paused = |err: anyhow::Error| matches!(err.downcast::<MasonryError>().unwrap(), MasonryError::Pause(PauseError::Paused {}));
+    suite.next_block();
+    assert!(masonry_paused(suite.stake("bob", ether(10)).unwrap_err()));
+    assert!(masonry_paused(suite.withdraw("carol", ether(10)).unwrap_err()));
+    assert!(masonry_paused(suite.claim_reward("carol").unwrap_err()));
+
+    // stakers can always leave
+    let tshare = suite.tshare.clone();
+    suite.masonry_execute("carol", &MasonryMsg::Exit {}).unwrap();
+    assert_eq!(suite.balance(&tshare, "carol"), ether(100));
+}
+
+#[test]
+fn setters_report_old_and_new_values() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let treasury = suite.treasury.clone();
+    let change = |parameter: &str, old: &str, new: &str| vec![
+        ("parameter".to_string(), parameter.to_string()),
+        ("old".to_string(), old.to_string()),
+        ("new".to_string(), new.to_string()),
+    ];
+
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::SetMaxSupplyExpansionPercents {
+        max_supply_expansion_percent: Uint128::from(300u128),
+    }).unwrap();
+    assert_eq!(attribute(&res, &treasury, "action"), Some("set max supply expansion percent".to_string()));
+    assert_eq!(events(&res, &treasury, "parameter_change"), vec![change("max_supply_expansion_percent", "400", "300")]);
+
+    // one event per parameter
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::SetBootstrap {
+        bootstrap_epochs: Uint128::from(4u128),
+        bootstrap_supply_expansion_percent: Uint128::from(450u128),
+    }).unwrap();
+    assert_eq!(events(&res, &treasury, "parameter_change"), vec![
+        change("bootstrap_epochs", "12", "4"),
+        change("bootstrap_supply_expansion_percent", "500", "450"),
+    ]);
+
+    // structured values are json
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::SetKeeperBounty {
+        bounty: Some(KeeperBounty::Fixed { amount: ether(1) }),
+    }).unwrap();
+    assert_eq!(events(&res, &treasury, "parameter_change"), vec![
+        change("keeper_bounty", "none", &format!("{{\"fixed\":{{\"amount\":\"{}\"}}}}", ether(1))),
+    ]);
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::SetGuardian { guardian: Some(Addr::unchecked("guardian")) }).unwrap();
+    assert_eq!(events(&res, &treasury, "parameter_change"), vec![change("guardian", "none", "guardian")]);
+
+    // setters executed from the timelock report the same change
+    let delay = Uint128::from(PERIOD);
+    suite.treasury_execute(&admin, &TreasuryMsg::SetTimelockDelay { delay: Some(delay) }).unwrap();
+    suite.treasury_execute(&admin, &TreasuryMsg::QueueTransaction {
+        msg: Box::new(TreasuryMsg::SetMaxDebtRatioPercent { max_debt_ratio_percent: Uint128::from(4_000u128) }),
+    }).unwrap();
+    suite.next_epoch();
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::ExecuteTransaction { id: 0 }).unwrap();
+    assert_eq!(events(&res, &treasury, "parameter_change"), vec![change("max_debt_ratio_percent", "3500", "4000")]);
+
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::QueueTransaction {
+        msg: Box::new(TreasuryMsg::MasonrySetLockup {
+            withdraw_lockup_epochs: Uint128::from(2u128),
+            reward_lockup_epochs: Uint128::from(1u128),
+        }),
+    }).unwrap();
+    assert_eq!(attribute(&res, &treasury, "id"), Some("1".to_string()));
+    suite.next_epoch();
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::ExecuteTransaction { id: 1 }).unwrap();
+    assert_eq!(attribute(&res, &treasury, "action"), Some("Masonry set lockup".to_string()));
+    assert_eq!(attribute(&res, &treasury, "withdraw_lockup_epochs"), Some("2".to_string()));
+}
+
+#[test]
+fn timelock_and_recoveries_report_events() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let treasury = suite.treasury.clone();
+    let masonry = suite.masonry.clone();
+    let pairs = |values: &[(&str, &str)]| -> Vec<(String, String)> {
+        values.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    };
+
+    // a token nobody meant to send the Treasury or the Masonry
+    let token_id = suite.app.contract_data(&suite.tomb).unwrap().code_id as u64;
+    let usdc = suite.app.instantiate_contract(token_id, suite.admin.clone(), &token::InstantiateMsg {
+        initial_balances: vec![
+            InitialBalance { address: treasury.to_string(), amount: ether(10) },
+            InitialBalance { address: masonry.to_string(), amount: ether(5) },
+        ],
+    }, &[], "USDC", None).unwrap();
+
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::GovernanceRecoverUnsupported {
+        token: usdc.clone(), amount: ether(10), to: Addr::unchecked("dao"),
+    }).unwrap();
+    assert_eq!(attribute(&res, &treasury, "action"), Some("governance recover unsupported".to_string()));
+    assert_eq!(events(&res, &treasury, "token_recovery"), vec![pairs(&[
+        ("holder", "treasury"), ("token", usdc.as_str()), ("amount", &ether(10).to_string()), ("to", "dao"),
+    ])]);
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::MasonryGovernanceRecoverUnsupported {
+        token: usdc.clone(), amount: ether(5), to: Addr::unchecked("dao"),
+    }).unwrap();
+    assert_eq!(events(&res, &treasury, "token_recovery"), vec![pairs(&[
+        ("holder", "masonry"), ("token", usdc.as_str()), ("amount", &ether(5).to_string()), ("to", "dao"),
+    ])]);
+    assert_eq!(suite.balance(&usdc, "dao"), ether(15));
+
+    // every status change of a queued setter
+    let delay = Uint128::from(PERIOD);
+    let set_debt_ratio = TreasuryMsg::SetMaxDebtRatioPercent { max_debt_ratio_percent: Uint128::from(4_000u128) };
+    let msg = r#"{"set_max_debt_ratio_percent":{"max_debt_ratio_percent":"4000"}}"#;
+    suite.treasury_execute(&admin, &TreasuryMsg::SetTimelockDelay { delay: Some(delay) }).unwrap();
+    let eta = (Uint128::from(suite.app.block_info().time.seconds()) + delay).to_string();
+    let transaction = |status: &str, id: &str| pairs(&[("status", status), ("id", id), ("eta", &eta), ("msg", msg)]);
+
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::QueueTransaction { msg: Box::new(set_debt_ratio.clone()) }).unwrap();
+    assert_eq!(events(&res, &treasury, "timelock_transaction"), vec![transaction("queued", "0")]);
+    suite.treasury_execute(&admin, &TreasuryMsg::QueueTransaction { msg: Box::new(set_debt_ratio) }).unwrap();
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::CancelTransaction { id: 1 }).unwrap();
+    assert_eq!(events(&res, &treasury, "timelock_transaction"), vec![transaction("cancelled", "1")]);
+    suite.next_epoch();
+    let res = suite.treasury_execute(&admin, &TreasuryMsg::ExecuteTransaction { id: 0 }).unwrap();
+    assert_eq!(events(&res, &treasury, "timelock_transaction"), vec![transaction("executed", "0")]);
+}
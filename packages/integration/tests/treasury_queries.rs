@@ -0,0 +1,449 @@
+use cosmwasm_std::{Addr, Uint128};
+
+use integration::suite::{ether, events, price, Suite, PERIOD};
+use lib::operator::OwnershipError;
+use IMasonry::msg::{ExecuteMsg as MasonryMsg, QueryMsg as MasonryQuery};
+use Treasury::msg::{
+    BondMarketResponse, ConfigResponse, EpochRecord, ExecuteMsg as TreasuryMsg, QueryMsg as TreasuryQuery, SeigniorageRecipient, SeigniorageShare, StateResponse,
+    SupplyTier,
+};
+use Treasury::ContractError as TreasuryError;
+
+fn treasury_error_msg(err: anyhow::Error) -> String {
+    match err.downcast::<TreasuryError>().unwrap() {
+        TreasuryError::TreasuryError { msg } => msg,
+        err => panic!("unexpected error {:?}", err),
+    }
+}
+
+#[test]
+fn config_returns_every_parameter() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+
+    let config: ConfigResponse = suite.treasury_query(&TreasuryQuery::Config {});
+    assert_eq!(config.operator, Some(suite.admin.clone()));
+    assert_eq!(config.tomb, suite.tomb);
+    assert_eq!(config.tbond, suite.tbond);
+    assert_eq!(config.tshare, suite.tshare);
+    assert_eq!(config.masonry, suite.masonry);
+    assert_eq!(config.bond_treasury, suite.bond_treasury);
+    assert_eq!(config.tomb_oracle, suite.oracle);
+    assert_eq!(config.start_time, Uint128::from(suite.start_time));
+    assert_eq!(config.period, Uint128::from(PERIOD));
+    assert_eq!(config.tomb_price_one, price(100));
+    assert_eq!(config.tomb_price_ceiling, price(101));
+    assert_eq!(config.supply_tiers.len(), 9);
+    assert_eq!(config.max_expansion_tiers[0], Uint128::from(450u128));
+    assert_eq!(config.max_debt_ratio_percent, Uint128::from(3_500u128));
+    assert_eq!(config.bootstrap_epochs, Uint128::from(12u128));
+    assert_eq!(config.bootstrap_supply_expansion_percent, Uint128::from(500u128));
+    assert_eq!(config.premium_threshold, Uint128::from(110u128));
+    assert!(config.seigniorage_recipients.is_empty());
+
+    let recipients = vec![
+        SeigniorageRecipient { name: "dao_fund".to_string(), address: suite.admin.clone(), share: Uint128::from(1_500u128) },
+        SeigniorageRecipient { name: "dev_fund".to_string(), address: suite.keeper.clone(), share: Uint128::from(500u128) },
+    ];
+    suite.treasury_execute(&admin, &TreasuryMsg::SetSeigniorageRecipients { recipients: recipients.clone() }).unwrap();
+
+    let config: ConfigResponse = suite.treasury_query(&TreasuryQuery::Config {});
+    assert_eq!(config.seigniorage_recipients, recipients);
+}
+
+#[test]
+fn state_follows_the_epochs() {
+    let mut suite = Suite::new();
+
+    let state: StateResponse = suite.treasury_query(&TreasuryQuery::State {});
+    assert_eq!(state, StateResponse {
+        epoch: Uint128::zero(),
+        next_epoch_point: Uint128::from(suite.start_time),
+        seigniorage_saved: Uint128::zero(),
+        epoch_supply_contraction_left: Uint128::zero(),
+        previous_epoch_tomb_price: Uint128::zero(),
+        // the genesis pool is excluded from the circulating supply
+        tomb_circulating_supply: ether(20_000),
+    });
+
+    suite.set_pair_price(price(120));
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+
+    let state: StateResponse = suite.treasury_query(&TreasuryQuery::State {});
+    assert_eq!(state.epoch, Uint128::from(1u128));
+    assert_eq!(state.next_epoch_point, Uint128::from(suite.start_time + PERIOD));
+    assert_eq!(state.previous_epoch_tomb_price, price(120));
+}
+
+#[test]
+fn bond_market_gathers_the_bond_queries() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBootstrap {
+        bootstrap_epochs: Uint128::zero(),
+        bootstrap_supply_expansion_percent: Uint128::from(500u128),
+    }).unwrap();
+    suite.set_pair_price(price(80));
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+    // the pair moves on, bonds keep trading at the TWAP until the next oracle update
+    suite.set_pair_price(price(85));
+
+    let market: BondMarketResponse = suite.treasury_query(&TreasuryQuery::BondMarket {});
+    let burnable: Uint128 = suite.treasury_query(&TreasuryQuery::GetBurnableTombLeft {});
+    let state: StateResponse = suite.treasury_query(&TreasuryQuery::State {});
+    assert_eq!(market, BondMarketResponse {
+        tomb_price: price(80),
+        spot_price: price(85),
+        discount_rate: price(100),
+        premium_rate: Uint128::zero(),
+        // 1,000 TBOND over 20,000 circulating TOMB
+        debt_ratio_percent: Uint128::from(500u128),
+        max_debt_ratio_percent: Uint128::from(3_500u128),
+        epoch_supply_contraction_left: state.epoch_supply_contraction_left,
+        burnable_tomb_left: burnable,
+        redeemable_bonds: Uint128::zero(),
+        treasury_tomb_balance: Uint128::zero(),
+    });
+    assert!(!burnable.is_zero());
+
+    // above the ceiling the expansion funds redemptions
+    suite.next_epoch();
+    suite.set_pair_price(price(120));
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+
+    let market: BondMarketResponse = suite.treasury_query(&TreasuryQuery::BondMarket {});
+    let premium_rate: Uint128 = suite.treasury_query(&TreasuryQuery::GetBondPremiumRate {});
+    let redeemable: Uint128 = suite.treasury_query(&TreasuryQuery::GetRedeemableBonds {});
+    let tomb = suite.tomb.clone();
+    let treasury = suite.treasury.to_string();
+    assert_eq!(market.tomb_price, price(120));
+    assert_eq!(market.premium_rate, premium_rate);
+    assert_eq!(market.epoch_supply_contraction_left, Uint128::zero());
+    assert_eq!(market.burnable_tomb_left, Uint128::zero());
+    assert_eq!(market.treasury_tomb_balance, suite.balance(&tomb, &treasury));
+    assert!(!market.treasury_tomb_balance.is_zero());
+    assert_eq!(market.redeemable_bonds, redeemable);
+    assert_eq!(market.redeemable_bonds, market.treasury_tomb_balance.multiply_ratio(price(100), premium_rate));
+}
+
+#[test]
+fn excluded_addresses_are_managed_by_the_operator() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let circulating = |suite: &Suite| suite.treasury_query::<StateResponse>(&TreasuryQuery::State {}).tomb_circulating_supply;
+    let add = |address: &str| TreasuryMsg::AddExcludedAddress { address: Addr::unchecked(address) };
+    let remove = |address: &str| TreasuryMsg::RemoveExcludedAddress { address: Addr::unchecked(address) };
+
+    let err = suite.treasury_execute("alice", &add("alice")).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::Unauthorized {}));
+
+    // alice's 10_000 TOMB stop counting as circulating
+    assert_eq!(circulating(&suite), ether(20_000));
+    suite.treasury_execute(&admin, &add("alice")).unwrap();
+    assert_eq!(circulating(&suite), ether(10_000));
+
+    let err = suite.treasury_execute(&admin, &add("alice")).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: address already excluded");
+
+    let excluded: Vec<Addr> = suite.treasury_query(&TreasuryQuery::ExcludedFromTotalSupply { start_after: None, limit: None });
+    assert_eq!(excluded, vec![suite.bond_treasury.clone(), Addr::unchecked("alice"), suite.genesis_pool.clone()]);
+
+    // pages follow address order
+    let page: Vec<Addr> = suite.treasury_query(&TreasuryQuery::ExcludedFromTotalSupply {
+        start_after: Some(Addr::unchecked("alice")),
+        limit: Some(1),
+    });
+    assert_eq!(page, vec![suite.genesis_pool.clone()]);
+
+    suite.treasury_execute(&admin, &remove("alice")).unwrap();
+    assert_eq!(circulating(&suite), ether(20_000));
+    let err = suite.treasury_execute(&admin, &remove("alice")).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: address not excluded");
+
+    // the list is capped, genesis pool and bond treasury already take two slots
+    for i in 0..18 {
+        suite.treasury_execute(&admin, &add(&format!("pool{}", i))).unwrap();
+    }
+    let err = suite.treasury_execute(&admin, &add("pool18")).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: too many excluded addresses");
+}
+
+#[test]
+fn epoch_history_records_each_allocation() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    // the epoch is counted before the bootstrap check, so this leaves a single bootstrap epoch
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBootstrap {
+        bootstrap_epochs: Uint128::from(2u128),
+        bootstrap_supply_expansion_percent: Uint128::from(450u128),
+    }).unwrap();
+
+    // bootstrap epoch at peg
+    suite.update_oracle().unwrap();
+    suite.allocate_seigniorage().unwrap();
+
+    // expansion at 1.20, capped by the first supply tier
+    suite.next_epoch();
+    suite.set_pair_price(price(120));
+    suite.update_oracle().unwrap();
+    let res = suite.allocate_seigniorage().unwrap();
+    let seigniorage = events(&res, &suite.treasury, "seigniorage");
+
+    let history: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: None, limit: None });
+    assert_eq!(history, vec![
+        EpochRecord {
+            epoch: 1,
+            tomb_price: price(100),
+            tomb_supply: ether(20_000),
+            bootstrap: true,
+            expansion_percent: Uint128::from(450u128),
+            to_masonry: ether(900),
+            to_recipients: vec![],
+            // 5% of the supply put up for sale as bonds
+            to_bond_treasury: ether(1_000),
+            to_reserve: Uint128::zero(),
+            to_keeper: Uint128::zero(),
+            circuit_breaker_tripped: false,
+            epoch_supply_contraction_left: ether(600),
+        },
+        EpochRecord {
+            epoch: 2,
+            tomb_price: price(120),
+            // the bootstrap allocation was minted into circulation
+            tomb_supply: ether(20_900),
+            bootstrap: false,
+            expansion_percent: Uint128::from(450u128),
+            // 35% for the Masonry while bonds are outstanding, the rest is saved to pay them
+            to_masonry: Uint128::from(329_175_000_000_000_000_000u128),
+            to_recipients: vec![],
+            // topped up to 5% of the grown supply
+            to_bond_treasury: ether(45),
+            to_reserve: Uint128::from(611_325_000_000_000_000_000u128),
+            to_keeper: Uint128::zero(),
+            circuit_breaker_tripped: false,
+            epoch_supply_contraction_left: Uint128::zero(),
+        },
+    ]);
+
+    // indexers get the same breakdown from the allocation itself
+    let attr = |key: &str, value: String| (key.to_string(), value);
+    assert_eq!(seigniorage, vec![vec![
+        attr("epoch", "2".to_string()),
+        attr("tomb_price", price(120).to_string()),
+        attr("tomb_supply", ether(20_900).to_string()),
+        attr("bootstrap", "false".to_string()),
+        attr("expansion_percent", "450".to_string()),
+        attr("to_masonry", history[1].to_masonry.to_string()),
+        attr("to_recipients", "[]".to_string()),
+        attr("to_bond_treasury", ether(45).to_string()),
+        attr("to_reserve", history[1].to_reserve.to_string()),
+        attr("to_keeper", "0".to_string()),
+        attr("circuit_breaker_tripped", "false".to_string()),
+        attr("epoch_supply_contraction_left", "0".to_string()),
+    ]]);
+
+    let page: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: Some(1), limit: Some(1) });
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].epoch, 2);
+}
+
+#[test]
+fn allocation_simulation_matches_the_next_allocation() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBootstrap {
+        bootstrap_epochs: Uint128::from(2u128),
+        bootstrap_supply_expansion_percent: Uint128::from(450u128),
+    }).unwrap();
+    suite.treasury_execute(&admin, &TreasuryMsg::SetSeigniorageRecipients { recipients: vec![
+        SeigniorageRecipient { name: "dao_fund".to_string(), address: Addr::unchecked("dao"), share: Uint128::from(1_000u128) },
+        SeigniorageRecipient { name: "dev_fund".to_string(), address: Addr::unchecked("dev"), share: Uint128::from(500u128) },
+    ] }).unwrap();
+
+    // bootstrap epoch, 10% and 5% of the 900 TOMB go to the recipients
+    suite.update_oracle().unwrap();
+    let simulation: EpochRecord = suite.treasury_query(&TreasuryQuery::SimulateAllocation {});
+    assert!(simulation.bootstrap);
+    assert_eq!(simulation.epoch, 1);
+    assert_eq!(simulation.to_masonry, ether(765));
+    assert_eq!(simulation.to_recipients, vec![
+        SeigniorageShare { name: "dao_fund".to_string(), amount: ether(90) },
+        SeigniorageShare { name: "dev_fund".to_string(), amount: ether(45) },
+    ]);
+    suite.allocate_seigniorage().unwrap();
+    let history: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: None, limit: None });
+    assert_eq!(history, vec![simulation]);
+
+    // simulating does not touch the state
+    suite.next_epoch();
+    suite.set_pair_price(price(120));
+    suite.update_oracle().unwrap();
+    let simulation: EpochRecord = suite.treasury_query(&TreasuryQuery::SimulateAllocation {});
+    assert!(!simulation.bootstrap);
+    assert_eq!(simulation.expansion_percent, Uint128::from(450u128));
+    assert_eq!(simulation.to_reserve, Uint128::from(611_325_000_000_000_000_000u128));
+    assert_eq!(suite.epoch(), Uint128::from(1u128));
+    let reserve: Uint128 = suite.treasury_query(&TreasuryQuery::GetReserve {});
+    assert_eq!(reserve, Uint128::zero());
+
+    suite.allocate_seigniorage().unwrap();
+    let history: Vec<EpochRecord> = suite.treasury_query(&TreasuryQuery::EpochHistory { start_after: Some(1), limit: None });
+    assert_eq!(history, vec![simulation]);
+}
+
+#[test]
+fn supply_tiers_are_replaced_as_a_whole() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let tier = |supply: u128, max_expansion_percent: u128| SupplyTier {
+        supply: ether(supply),
+        max_expansion_percent: Uint128::from(max_expansion_percent),
+    };
+
+    let tiers: Vec<SupplyTier> = suite.treasury_query(&TreasuryQuery::SupplyTiers {});
+    assert_eq!(tiers.len(), 9);
+    assert_eq!(tiers[0], tier(0, 450));
+    assert_eq!(tiers[8], tier(50_000_000, 100));
+
+    let set = |tiers: Vec<SupplyTier>| TreasuryMsg::SetSupplyTiers { tiers };
+    let err = suite.treasury_execute("alice", &set(vec![tier(0, 200)])).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::Unauthorized {}));
+    let err = suite.treasury_execute(&admin, &set(vec![])).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: between 1 and 20 supply tiers");
+    let err = suite.treasury_execute(&admin, &set(vec![tier(0, 200), tier(10_000, 150), tier(10_000, 100)])).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: supply tiers must be strictly ascending");
+    let err = suite.treasury_execute(&admin, &set(vec![tier(0, 200), tier(10_000, 5)])).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::ValueOutOfRange {}));
+    let err = suite.treasury_execute(&admin, &set(vec![tier(0, 100), tier(10_000, 200)])).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: expansion caps cannot increase with the supply");
+
+    // a failed replacement leaves the table untouched
+    let unchanged: Vec<SupplyTier> = suite.treasury_query(&TreasuryQuery::SupplyTiers {});
+    assert_eq!(unchanged, tiers);
+
+    suite.treasury_execute(&admin, &set(vec![tier(0, 200), tier(10_000, 100)])).unwrap();
+    let tiers: Vec<SupplyTier> = suite.treasury_query(&TreasuryQuery::SupplyTiers {});
+    assert_eq!(tiers, vec![tier(0, 200), tier(10_000, 100)]);
+    let config: ConfigResponse = suite.treasury_query(&TreasuryQuery::Config {});
+    assert_eq!(config.supply_tiers, vec![ether(0), ether(10_000)]);
+    assert_eq!(config.max_expansion_tiers, vec![Uint128::from(200u128), Uint128::from(100u128)]);
+
+    // 20_000 circulating TOMB is past the last tier, so the expansion at 1.20 is capped at 1%
+    suite.treasury_execute(&admin, &TreasuryMsg::SetBootstrap {
+        bootstrap_epochs: Uint128::from(1u128),
+        bootstrap_supply_expansion_percent: Uint128::from(450u128),
+    }).unwrap();
+    suite.set_pair_price(price(120));
+    suite.update_oracle().unwrap();
+    let simulation: EpochRecord = suite.treasury_query(&TreasuryQuery::SimulateAllocation {});
+    assert_eq!(simulation.expansion_percent, Uint128::from(100u128));
+
+    // entries are edited within the bounds of their neighbours
+    let err = suite.treasury_execute(&admin, &TreasuryMsg::SetSupplyTiersEntry {
+        index: Uint128::from(2u128),
+        value: ether(30_000),
+    }).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::IndexOutOfRange {}));
+    suite.treasury_execute(&admin, &TreasuryMsg::SetSupplyTiersEntry {
+        index: Uint128::from(1u128),
+        value: ether(30_000),
+    }).unwrap();
+    let err = suite.treasury_execute(&admin, &TreasuryMsg::SetMaxExpansionTiersEntry {
+        index: Uint128::from(1u128),
+        value: Uint128::from(250u128),
+    }).unwrap_err();
+    assert_eq!(treasury_error_msg(err), "Treasury: expansion caps cannot increase with the supply");
+    suite.treasury_execute(&admin, &TreasuryMsg::SetMaxExpansionTiersEntry {
+        index: Uint128::zero(),
+        value: Uint128::from(300u128),
+    }).unwrap();
+    let simulation: EpochRecord = suite.treasury_query(&TreasuryQuery::SimulateAllocation {});
+    assert_eq!(simulation.expansion_percent, Uint128::from(300u128));
+}
+
+#[test]
+fn initialize_runs_once() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let mallory = Addr::unchecked("mallory");
+    let initialize = TreasuryMsg::Initialize {
+        tomb: suite.tomb.clone(),
+        tbond: suite.tbond.clone(),
+        tshare: suite.tshare.clone(),
+        tomb_oracle: mallory.clone(),
+        masonry: suite.masonry.clone(),
+        genesis_pool: suite.genesis_pool.clone(),
+        bond_treasury: mallory.clone(),
+        start_time: Uint128::from(suite.start_time),
+        period: Uint128::from(PERIOD),
+    };
+    let initialized: bool = suite.treasury_query(&TreasuryQuery::IsInitialized {});
+    assert!(initialized);
+
+    for sender in ["mallory", admin.as_str()] {
+        let err = suite.treasury_execute(sender, &initialize).unwrap_err();
+        assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::AlreadyInitialized {}));
+    }
+    let config: ConfigResponse = suite.treasury_query(&TreasuryQuery::Config {});
+    assert_eq!(config.operator, Some(suite.admin.clone()));
+    assert_eq!(config.bond_treasury, suite.bond_treasury);
+}
+
+#[test]
+fn operator_is_handed_over_in_two_steps() {
+    let mut suite = Suite::new();
+    let admin = suite.admin.to_string();
+    let alice = Addr::unchecked("alice");
+    let ownership_error = |err: anyhow::Error| match err.downcast::<TreasuryError>().unwrap() {
+        TreasuryError::Ownership(err) => err,
+        err => panic!("unexpected error {:?}", err),
+    };
+
+    let err = suite.treasury_execute("alice", &TreasuryMsg::ProposeOperator { operator: alice.clone() }).unwrap_err();
+    assert_eq!(ownership_error(err), OwnershipError::Unauthorized {});
+    let err = suite.treasury_execute("alice", &TreasuryMsg::AcceptOperator {}).unwrap_err();
+    assert_eq!(ownership_error(err), OwnershipError::NoProposal {});
+
+    suite.treasury_execute(&admin, &TreasuryMsg::ProposeOperator { operator: alice.clone() }).unwrap();
+    let pending: Option<Addr> = suite.treasury_query(&TreasuryQuery::PendingOperator {});
+    assert_eq!(pending, Some(alice.clone()));
+    let err = suite.treasury_execute("bob", &TreasuryMsg::AcceptOperator {}).unwrap_err();
+    assert_eq!(ownership_error(err), OwnershipError::Unauthorized {});
+
+    // the admin keeps operating the Treasury until alice accepts
+    let config: ConfigResponse = suite.treasury_query(&TreasuryQuery::Config {});
+    assert_eq!(config.operator, Some(suite.admin.clone()));
+    suite.treasury_execute("alice", &TreasuryMsg::AcceptOperator {}).unwrap();
+    let config: ConfigResponse = suite.treasury_query(&TreasuryQuery::Config {});
+    assert_eq!(config.operator, Some(alice.clone()));
+    let pending: Option<Addr> = suite.treasury_query(&TreasuryQuery::PendingOperator {});
+    assert_eq!(pending, None);
+    let period = TreasuryMsg::SetPeriod { period: Uint128::from(PERIOD) };
+    let err = suite.treasury_execute(&admin, &period).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::Unauthorized {}));
+    suite.treasury_execute("alice", &period).unwrap();
+
+    // the Treasury hands the Masonry over the same way
+    suite.treasury_execute("alice", &TreasuryMsg::MasonryProposeOperator { operator: alice.clone() }).unwrap();
+    let masonry = suite.masonry.clone();
+    let pending: Option<Addr> = suite.query(&masonry, &MasonryQuery::PendingOperator {});
+    assert_eq!(pending, Some(alice.clone()));
+    suite.masonry_execute("alice", &MasonryMsg::CancelProposal {}).unwrap_err();
+    suite.treasury_execute("alice", &TreasuryMsg::MasonryAcceptOperator {}).unwrap_err();
+    suite.masonry_execute("alice", &MasonryMsg::AcceptOperator {}).unwrap();
+    let operator: Addr = suite.query(&masonry, &MasonryQuery::Operator {});
+    assert_eq!(operator, alice);
+
+    // once renounced nobody operates the Treasury anymore
+    suite.treasury_execute("alice", &TreasuryMsg::ProposeOperator { operator: suite.admin.clone() }).unwrap();
+    suite.treasury_execute("alice", &TreasuryMsg::RenounceOperator {}).unwrap();
+    let config: ConfigResponse = suite.treasury_query(&TreasuryQuery::Config {});
+    assert_eq!(config.operator, None);
+    let err = suite.treasury_execute(&admin, &TreasuryMsg::AcceptOperator {}).unwrap_err();
+    assert_eq!(ownership_error(err), OwnershipError::NoProposal {});
+    let err = suite.treasury_execute("alice", &period).unwrap_err();
+    assert!(matches!(err.downcast::<TreasuryError>().unwrap(), TreasuryError::Unauthorized {}));
+}
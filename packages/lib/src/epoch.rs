@@ -1,6 +1,5 @@
 use cosmwasm_std::{StdResult, StdError, Addr};
 use serde::{Deserialize, Serialize};
-use crate::operator::{Operator};
 
 use chrono::prelude::*;
 
@@ -10,7 +9,7 @@ pub struct Epoch{
     start_time: u128,
     last_epoch_time: u128,
     epoch: u128,
-    operator: Operator
+    operator: Addr
 }
 
 impl Epoch{
@@ -25,7 +24,7 @@ impl Epoch{
         self.start_time = _start_time;
         self.epoch = _start_epoch;
         self.last_epoch_time = _start_time - _period; 
-        self.operator = Operator::new(_sender);
+        self.operator = _sender;
     }
 
     pub fn checkStartTime(self) -> StdResult<bool> {
@@ -60,10 +59,19 @@ impl Epoch{
 
     /* ========== GOVERNANCE ========== */
 
+    fn check_operator(&self, sender: Addr) -> StdResult<bool> {
+        if self.operator != sender {
+            return Err(StdError::GenericErr{
+                msg: "Not Authorized".to_string()
+            });
+        }
+        Ok(true)
+    }
+
     pub fn setPeriod(mut self, _period: u128, sender: Addr) 
         -> StdResult<bool>
     {
-        self.operator.isOperator(sender)?;
+        self.check_operator(sender)?;
         if _period < 1 || _period <= 48 * 60 * 60 {
             return Err(StdError::GenericErr{
                 msg: "_period: out of range".to_string()
@@ -76,7 +84,7 @@ impl Epoch{
     pub fn setEpoch(mut self, _epoch: u128, sender: Addr)
         -> StdResult<bool>
     {
-        self.operator.isOperator(sender)?;
+        self.check_operator(sender)?;
         self.epoch = _epoch;
         Ok(true)
     }
@@ -73,7 +73,7 @@ impl FixedPoint{
     // returns a UQ112x112 which represents the ratio of the numerator to the denominator
     // equivalent to encode(numerator).div(denominator)
     pub fn fraction(self, numerator: u128, denominator: u128) -> StdResult<uq112x112> {
-        if denominator <=0 {
+        if denominator == 0 {
             return Err(StdError::GenericErr{
                 msg: "FixedPoint: DIV_BY_ZERO".to_string()
             })
@@ -4,4 +4,10 @@ pub mod state;
 pub mod babylonian;
 pub mod fixed_point;
 pub mod epoch;
-pub mod operator;
\ No newline at end of file
+pub mod operator;
+pub mod pause;
+pub mod migrate;
+
+// mock querier shared by the contract unit tests
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mock_querier;
@@ -0,0 +1,56 @@
+use cosmwasm_std::{StdError, Storage};
+use cw2::{get_contract_version, set_contract_version};
+pub use semver::Version;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MigrateError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Migrate: cannot migrate {stored} into {expected}")]
+    WrongContract { stored: String, expected: String },
+
+    #[error("Migrate: invalid version {version}")]
+    InvalidVersion { version: String },
+
+    #[error("Migrate: cannot downgrade from {stored} to {new}")]
+    Downgrade { stored: String, new: String },
+}
+
+fn parse_version(version: &str) -> Result<Version, MigrateError> {
+    Version::parse(version).map_err(|_| MigrateError::InvalidVersion {
+        version: version.to_string(),
+    })
+}
+
+// Checks the cw2 info stored by the running code against the code being migrated to
+// and records the new version. `legacy_names` lists names the contract was deployed under before.
+// Returns the version being migrated from, so the caller can run the storage migrations it needs.
+pub fn migrate_version(
+    storage: &mut dyn Storage,
+    name: &str,
+    version: &str,
+    legacy_names: &[&str],
+) -> Result<Version, MigrateError> {
+    let stored = get_contract_version(storage)?;
+    if stored.contract != name && !legacy_names.contains(&stored.contract.as_str()) {
+        return Err(MigrateError::WrongContract {
+            stored: stored.contract,
+            expected: name.to_string(),
+        });
+    }
+
+    let stored_version = parse_version(&stored.version)?;
+    let new_version = parse_version(version)?;
+    if stored_version > new_version {
+        return Err(MigrateError::Downgrade {
+            stored: stored.version,
+            new: version.to_string(),
+        });
+    }
+
+    set_contract_version(storage, name, version)?;
+    Ok(stored_version)
+}
+
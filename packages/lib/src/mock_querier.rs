@@ -4,8 +4,35 @@ use cosmwasm_std::{
     QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
 };
 use cw20::{BalanceResponse, Cw20QueryMsg};
+use serde::Deserialize;
 use std::collections::HashMap;
 
+const ETHER: u128 = 1_000_000_000_000_000_000u128;
+
+// Consult of the Oracle contract, which depends on this crate
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum OracleQueryMsg {
+    Consult { token: AssetInfo, amount_in: Uint128 },
+}
+
+// terraswap AssetInfo, keyed like its Display
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum AssetInfo {
+    Token { contract_addr: String },
+    NativeToken { denom: String },
+}
+
+impl std::fmt::Display for AssetInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssetInfo::Token { contract_addr } => write!(f, "{}", contract_addr),
+            AssetInfo::NativeToken { denom } => write!(f, "{}", denom),
+        }
+    }
+}
+
 /// mock_dependencies is a drop-in replacement for cosmwasm_std::testing::mock_dependencies
 /// this uses our CustomQuerier.
 pub fn mock_dependencies(
@@ -26,6 +53,7 @@ pub fn mock_dependencies(
 pub struct WasmMockQuerier {
     base: MockQuerier,
     token_querier: TokenQuerier,
+    oracle_querier: OracleQuerier,
 }
 
 #[derive(Clone, Default)]
@@ -56,6 +84,20 @@ pub(crate) fn balances_to_map(
     contract_map
 }
 
+#[derive(Clone, Default)]
+pub struct OracleQuerier {
+    // oracle address -> token -> price of one ether unit
+    prices: HashMap<String, HashMap<String, Uint128>>,
+}
+
+impl OracleQuerier {
+    pub fn new(prices: &[(String, &[(String, Uint128)])]) -> Self {
+        OracleQuerier {
+            prices: balances_to_map(prices),
+        }
+    }
+}
+
 impl Querier for WasmMockQuerier {
     fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
         // MockQuerier doesn't support Custom, so we ignore it completely here
@@ -75,8 +117,25 @@ impl Querier for WasmMockQuerier {
 impl WasmMockQuerier {
     pub fn handle_query(&self, request: &QueryRequest<Empty>) -> QuerierResult {
         match &request {
-            QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => {
-                match from_binary(msg).unwrap() {
+            QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => match from_binary(msg) {
+                Ok(OracleQueryMsg::Consult { token, amount_in }) => {
+                    match self.oracle_querier.prices.get(contract_addr) {
+                        Some(prices_map) => match prices_map.get(&token.to_string()) {
+                            Some(price) => SystemResult::Ok(ContractResult::from(to_binary(
+                                &price.multiply_ratio(amount_in, Uint128::from(ETHER)),
+                            ))),
+                            None => SystemResult::Err(SystemError::InvalidRequest {
+                                error: "No price found in oracle price map".to_string(),
+                                request: msg.as_slice().into(),
+                            }),
+                        },
+                        None => SystemResult::Err(SystemError::InvalidRequest {
+                            error: "No oracle contract found in price map".to_string(),
+                            request: msg.as_slice().into(),
+                        }),
+                    }
+                }
+                _ => match from_binary(msg).unwrap() {
                     Cw20QueryMsg::Balance { address } => {
                         match self.token_querier.balances.get(contract_addr) {
                             Some(balances_map) => match balances_map.get(&address) {
@@ -98,8 +157,8 @@ impl WasmMockQuerier {
                         error: "Not supported query".to_string(),
                         request: msg.as_slice().into(),
                     }),
-                }
-            }
+                },
+            },
             _ => self.base.handle_query(request),
         }
     }
@@ -110,6 +169,7 @@ impl WasmMockQuerier {
         WasmMockQuerier {
             base,
             token_querier: TokenQuerier::default(),
+            oracle_querier: OracleQuerier::default(),
         }
     }
 
@@ -117,4 +177,9 @@ impl WasmMockQuerier {
     pub fn with_token_balances(&mut self, balances: &[(String, &[(String, Uint128)])]) {
         self.token_querier = TokenQuerier::new(balances);
     }
+
+    // configure the oracle price mock querier
+    pub fn with_oracle_prices(&mut self, prices: &[(String, &[(String, Uint128)])]) {
+        self.oracle_querier = OracleQuerier::new(prices);
+    }
 }
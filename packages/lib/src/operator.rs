@@ -1,23 +1,125 @@
-use cosmwasm_std::{StdResult, StdError, Addr};
-use serde::{Deserialize, Serialize};
+use cosmwasm_std::{StdResult, StdError, Addr, Response, Storage};
+use cw_storage_plus::Item;
+use thiserror::Error;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
-pub struct Operator{
-    operator: Addr
+#[derive(Error, Debug, PartialEq)]
+pub enum OwnershipError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Ownership: no operator proposed")]
+    NoProposal {},
+}
+
+// Two-step hand over of the operator of a contract: the operator proposes a
+// successor, who only takes over once it accepts. Contracts keep their own
+// operator item, the pending one lives next to it.
+pub struct Ownership<'a> {
+    pub operator: &'a Item<'a, Addr>,
+    pub pending: &'a Item<'a, Addr>,
 }
 
-impl Operator {
-    pub fn new(_operator: Addr) -> Operator{
-        Operator{
-            operator: _operator
+impl<'a> Ownership<'a> {
+    pub const fn new(operator: &'a Item<'a, Addr>, pending: &'a Item<'a, Addr>) -> Self {
+        Ownership { operator, pending }
+    }
+
+    // a renounced contract has no operator left
+    pub fn assert_operator(&self, storage: &dyn Storage, sender: &Addr) -> Result<(), OwnershipError> {
+        match self.operator.may_load(storage)? {
+            Some(operator) if operator == *sender => Ok(()),
+            _ => Err(OwnershipError::Unauthorized {}),
+        }
+    }
+
+    pub fn pending_operator(&self, storage: &dyn Storage) -> StdResult<Option<Addr>> {
+        self.pending.may_load(storage)
+    }
+
+    // replaces any earlier proposal
+    pub fn propose(&self, storage: &mut dyn Storage, sender: &Addr, operator: Addr) -> Result<Response, OwnershipError> {
+        self.assert_operator(storage, sender)?;
+        self.pending.save(storage, &operator)?;
+        Ok(Response::new()
+            .add_attribute("action", "propose operator")
+            .add_attribute("pending_operator", operator))
+    }
+
+    pub fn accept(&self, storage: &mut dyn Storage, sender: &Addr) -> Result<Response, OwnershipError> {
+        let pending = self.pending.may_load(storage)?.ok_or(OwnershipError::NoProposal {})?;
+        if pending != *sender {
+            return Err(OwnershipError::Unauthorized {});
         }
+        let previous = self.operator.may_load(storage)?;
+        self.operator.save(storage, &pending)?;
+        self.pending.remove(storage);
+        Ok(Response::new()
+            .add_attribute("action", "accept operator")
+            .add_attribute("previous_operator", previous.map(String::from).unwrap_or_else(|| "none".to_string()))
+            .add_attribute("operator", pending))
     }
-    pub fn isOperator(&self, sender: Addr) -> StdResult<bool>{
-        if self.operator != sender {
-            return Err(StdError::GenericErr{
-                msg: "Not Authorized".to_string()
-            });
+
+    pub fn cancel(&self, storage: &mut dyn Storage, sender: &Addr) -> Result<Response, OwnershipError> {
+        self.assert_operator(storage, sender)?;
+        if self.pending.may_load(storage)?.is_none() {
+            return Err(OwnershipError::NoProposal {});
         }
-        Ok(true)
+        self.pending.remove(storage);
+        Ok(Response::new().add_attribute("action", "cancel operator proposal"))
+    }
+
+    // irreversible, operator only messages are rejected from then on
+    pub fn renounce(&self, storage: &mut dyn Storage, sender: &Addr) -> Result<Response, OwnershipError> {
+        self.assert_operator(storage, sender)?;
+        self.operator.remove(storage);
+        self.pending.remove(storage);
+        Ok(Response::new()
+            .add_attribute("action", "renounce operator")
+            .add_attribute("previous_operator", sender.as_str()))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+
+    const OPERATOR: Item<Addr> = Item::new("operator");
+    const PENDING_OPERATOR: Item<Addr> = Item::new("pending operator");
+    const OWNERSHIP: Ownership = Ownership::new(&OPERATOR, &PENDING_OPERATOR);
+
+    #[test]
+    fn operator_is_handed_over_in_two_steps() {
+        let mut storage = MockStorage::new();
+        let (admin, alice, bob) = (Addr::unchecked("admin"), Addr::unchecked("alice"), Addr::unchecked("bob"));
+        OPERATOR.save(&mut storage, &admin).unwrap();
+
+        assert_eq!(OWNERSHIP.propose(&mut storage, &alice, bob.clone()), Err(OwnershipError::Unauthorized {}));
+        assert_eq!(OWNERSHIP.accept(&mut storage, &bob), Err(OwnershipError::NoProposal {}));
+        assert_eq!(OWNERSHIP.cancel(&mut storage, &admin), Err(OwnershipError::NoProposal {}));
+
+        // a proposal can be replaced or cancelled before it is accepted
+        OWNERSHIP.propose(&mut storage, &admin, alice.clone()).unwrap();
+        OWNERSHIP.propose(&mut storage, &admin, bob.clone()).unwrap();
+        assert_eq!(OWNERSHIP.pending_operator(&storage).unwrap(), Some(bob.clone()));
+        assert_eq!(OWNERSHIP.accept(&mut storage, &alice), Err(OwnershipError::Unauthorized {}));
+        OWNERSHIP.cancel(&mut storage, &admin).unwrap();
+        assert_eq!(OWNERSHIP.accept(&mut storage, &bob), Err(OwnershipError::NoProposal {}));
+
+        OWNERSHIP.propose(&mut storage, &admin, bob.clone()).unwrap();
+        OWNERSHIP.accept(&mut storage, &bob).unwrap();
+        assert_eq!(OPERATOR.load(&storage).unwrap(), bob);
+        assert_eq!(OWNERSHIP.pending_operator(&storage).unwrap(), None);
+        assert_eq!(OWNERSHIP.assert_operator(&storage, &admin), Err(OwnershipError::Unauthorized {}));
+
+        // nobody can operate a renounced contract
+        OWNERSHIP.propose(&mut storage, &bob, alice.clone()).unwrap();
+        OWNERSHIP.renounce(&mut storage, &bob).unwrap();
+        assert_eq!(OPERATOR.may_load(&storage).unwrap(), None);
+        assert_eq!(OWNERSHIP.accept(&mut storage, &alice), Err(OwnershipError::NoProposal {}));
+        assert_eq!(OWNERSHIP.assert_operator(&storage, &bob), Err(OwnershipError::Unauthorized {}));
+    }
+}
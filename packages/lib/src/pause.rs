@@ -0,0 +1,156 @@
+use cosmwasm_std::{to_vec, Addr, Response, StdError, StdResult, Storage};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PauseError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Pause: operation is paused")]
+    Paused {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseStatus<T> {
+    pub guardian: Option<Addr>,
+    pub paused: Vec<T>,
+}
+
+// Emergency switch over the operations `T` of a contract. The operator appoints
+// a guardian, who pauses and resumes them. Exit paths are never operations.
+pub struct Pause<'a, T> {
+    pub guardian: &'a Item<'a, Addr>,
+    pub paused: &'a Item<'a, Vec<T>>,
+}
+
+impl<'a, T> Pause<'a, T> {
+    pub const fn new(guardian: &'a Item<'a, Addr>, paused: &'a Item<'a, Vec<T>>) -> Self {
+        Pause { guardian, paused }
+    }
+}
+
+impl<'a, T> Pause<'a, T>
+where
+    T: Serialize + DeserializeOwned + PartialEq,
+{
+    pub fn is_paused(&self, storage: &dyn Storage, operation: &T) -> StdResult<bool> {
+        Ok(self.paused.may_load(storage)?.unwrap_or_default().contains(operation))
+    }
+
+    pub fn assert_not_paused(&self, storage: &dyn Storage, operation: &T) -> Result<(), PauseError> {
+        if self.is_paused(storage, operation)? {
+            return Err(PauseError::Paused {});
+        }
+        Ok(())
+    }
+
+    pub fn status(&self, storage: &dyn Storage) -> StdResult<PauseStatus<T>> {
+        Ok(PauseStatus {
+            guardian: self.guardian.may_load(storage)?,
+            paused: self.paused.may_load(storage)?.unwrap_or_default(),
+        })
+    }
+
+    // the caller checks the operator, None leaves nobody able to pause
+    pub fn set_guardian(&self, storage: &mut dyn Storage, guardian: Option<Addr>) -> Result<Response, PauseError> {
+        match &guardian {
+            Some(guardian) => self.guardian.save(storage, guardian)?,
+            None => self.guardian.remove(storage),
+        }
+        Ok(Response::new()
+            .add_attribute("action", "set guardian")
+            .add_attribute("guardian", guardian.map(String::from).unwrap_or_else(|| "none".to_string())))
+    }
+
+    pub fn pause(&self, storage: &mut dyn Storage, sender: &Addr, operations: Vec<T>) -> Result<Response, PauseError> {
+        self.assert_guardian(storage, sender)?;
+        let attribute = operations_attribute(&operations)?;
+        self.add_paused(storage, operations)?;
+        Ok(Response::new()
+            .add_attribute("action", "pause")
+            .add_attribute("operations", attribute))
+    }
+
+    // pause triggered by the contract itself
+    pub fn add_paused(&self, storage: &mut dyn Storage, operations: Vec<T>) -> StdResult<()> {
+        let mut paused = self.paused.may_load(storage)?.unwrap_or_default();
+        for operation in operations {
+            if !paused.contains(&operation) {
+                paused.push(operation);
+            }
+        }
+        self.paused.save(storage, &paused)
+    }
+
+    // resume triggered by the contract itself
+    pub fn remove_paused(&self, storage: &mut dyn Storage, operations: &[T]) -> StdResult<()> {
+        let mut paused = self.paused.may_load(storage)?.unwrap_or_default();
+        paused.retain(|operation| !operations.contains(operation));
+        self.paused.save(storage, &paused)
+    }
+
+    pub fn unpause(&self, storage: &mut dyn Storage, sender: &Addr, operations: Vec<T>) -> Result<Response, PauseError> {
+        self.assert_guardian(storage, sender)?;
+        self.remove_paused(storage, &operations)?;
+        Ok(Response::new()
+            .add_attribute("action", "unpause")
+            .add_attribute("operations", operations_attribute(&operations)?))
+    }
+
+    fn assert_guardian(&self, storage: &dyn Storage, sender: &Addr) -> Result<(), PauseError> {
+        match self.guardian.may_load(storage)? {
+            Some(guardian) if guardian == *sender => Ok(()),
+            _ => Err(PauseError::Unauthorized {}),
+        }
+    }
+}
+
+// operations as their json names, e.g. ["stake","claim_reward"]
+fn operations_attribute<T: Serialize>(operations: &[T]) -> StdResult<String> {
+    String::from_utf8(to_vec(operations)?).map_err(StdError::invalid_utf8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+    #[serde(rename_all = "snake_case")]
+    enum Operation {
+        Stake,
+        ClaimReward,
+    }
+
+    const GUARDIAN: Item<Addr> = Item::new("guardian");
+    const PAUSED: Item<Vec<Operation>> = Item::new("paused");
+    const PAUSE: Pause<Operation> = Pause::new(&GUARDIAN, &PAUSED);
+
+    #[test]
+    fn guardian_pauses_selected_operations() {
+        let mut storage = MockStorage::new();
+        let guardian = Addr::unchecked("guardian");
+        assert_eq!(PAUSE.pause(&mut storage, &guardian, vec![Operation::Stake]), Err(PauseError::Unauthorized {}));
+
+        PAUSE.set_guardian(&mut storage, Some(guardian.clone())).unwrap();
+        let res = PAUSE.pause(&mut storage, &guardian, vec![Operation::Stake, Operation::Stake]).unwrap();
+        assert_eq!(res.attributes[1].value, "[\"stake\",\"stake\"]");
+        assert_eq!(PAUSE.assert_not_paused(&storage, &Operation::Stake), Err(PauseError::Paused {}));
+        assert_eq!(PAUSE.assert_not_paused(&storage, &Operation::ClaimReward), Ok(()));
+        assert_eq!(PAUSE.status(&storage).unwrap(), PauseStatus {
+            guardian: Some(guardian.clone()),
+            paused: vec![Operation::Stake],
+        });
+
+        assert_eq!(PAUSE.unpause(&mut storage, &Addr::unchecked("alice"), vec![Operation::Stake]), Err(PauseError::Unauthorized {}));
+        PAUSE.unpause(&mut storage, &guardian, vec![Operation::Stake, Operation::ClaimReward]).unwrap();
+        assert_eq!(PAUSE.is_paused(&storage, &Operation::Stake), Ok(false));
+    }
+}